@@ -0,0 +1,2229 @@
+//! Documentation for this crate is work in progress.
+//!
+//! For now, please see the C API documentation at <https://developer.rebble.io/developer.pebble.com/docs/c/index.html> for more information.
+
+#![no_std]
+#![doc(html_root_url = "https://docs.rs/pebble-sys/0.0.1")]
+#![feature(extern_types)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::match_bool)]
+// Matching the SDK documentation.
+#![allow(clippy::module_name_repetitions)]
+
+use core::panic::PanicInfo;
+use foundation::logging::app_log;
+use standard_c::memory::c_str;
+
+pub mod prelude {
+	pub use super::standard_c::prelude::*;
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+	unsafe {
+		let panic = &*("### PANIC ###\0" as *const str as *const _ as *const c_str);
+		let todo = &*("TODO: Output trace somehow.\0" as *const str as *const _ as *const c_str);
+		app_log(1, panic, -1, todo);
+	}
+	loop {}
+}
+
+extern "C" {
+	/// Marker type for unsized newtypes.
+	type ExternData;
+}
+
+pub mod foundation {
+	pub mod app {
+		extern "C" {
+			pub fn app_event_loop();
+		}
+	}
+
+	pub mod app_worker {
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum AppWorkerResult {
+			Success = 0,
+			NoWorker = 1,
+			DifferentApp = 2,
+			NotRunning = 3,
+			AlreadyRunning = 4,
+			AskingConfirmation = 5,
+		}
+
+		#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub struct AppWorkerMessage {
+			pub data0: u16,
+			pub data1: u16,
+			pub data2: u16,
+		}
+
+		pub type AppWorkerMessageHandler = extern "C" fn(r#type: u16, data: &AppWorkerMessage);
+
+		extern "C" {
+			pub fn app_worker_is_running() -> bool;
+			pub fn app_worker_launch() -> AppWorkerResult;
+			pub fn app_worker_kill() -> AppWorkerResult;
+			pub fn app_worker_message_subscribe(handler: AppWorkerMessageHandler) -> bool;
+			pub fn app_worker_message_unsubscribe() -> bool;
+			pub fn app_worker_send_message(r#type: u8, data: &AppWorkerMessage);
+		}
+	}
+
+	pub mod data_logging {
+		use crate::standard_c::memory::void;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum DataLoggingItemType {
+			ByteArray = 0,
+			Uint = 2,
+			Int = 3,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum DataLoggingResult {
+			Success = 0,
+			Busy,
+			Full,
+			NotFound,
+			Closed,
+			InvalidParams,
+			InternalErr,
+		}
+
+		extern "C" {
+			pub type DataLoggingSession;
+
+			pub fn data_logging_create(
+				tag: u32,
+				item_type: DataLoggingItemType,
+				item_length: u16,
+				resume: bool,
+			) -> Option<&'static mut DataLoggingSession>;
+			pub fn data_logging_finish(logging_session: &'static mut DataLoggingSession);
+			pub fn data_logging_log(
+				logging_session: &mut DataLoggingSession,
+				data: *const void,
+				num_items: u32,
+			) -> DataLoggingResult;
+		}
+	}
+
+	pub mod dictation {
+		use crate::standard_c::memory::{c_str, void};
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum DictationSessionStatus {
+			Success = 0,
+			FailureTranscriptionRejected,
+			FailureTranscriptionRejectedWithError,
+			FailureSystemAborted,
+			FailureNoSpeechDetected,
+			FailureConnectivityError,
+			FailureDisabled,
+			FailureInternalError,
+			FailureRecognizerError,
+		}
+
+		pub type DictationSessionStatusCallback = extern "C" fn(
+			session: &mut DictationSession,
+			status: DictationSessionStatus,
+			transcription: *mut c_str,
+			context: *mut void,
+		);
+
+		extern "C" {
+			pub type DictationSession;
+
+			pub fn dictation_session_create(
+				buffer_size: u32,
+				callback: DictationSessionStatusCallback,
+				callback_context: *mut void,
+			) -> Option<&'static mut DictationSession>;
+			pub fn dictation_session_destroy(session: &'static mut DictationSession);
+			pub fn dictation_session_enable_confirmation(
+				session: &mut DictationSession,
+				is_enabled: bool,
+			);
+			pub fn dictation_session_enable_error_dialogs(
+				session: &mut DictationSession,
+				is_enabled: bool,
+			);
+			pub fn dictation_session_start(
+				session: &mut DictationSession,
+			) -> DictationSessionStatus;
+			pub fn dictation_session_stop(session: &mut DictationSession)
+				-> DictationSessionStatus;
+		}
+	}
+
+	pub mod event_service {
+		pub mod accelerometer_service {
+			#[derive(Debug, Clone, Copy)]
+			#[repr(C)]
+			pub struct AccelData {
+				pub x: i16,
+				pub y: i16,
+				pub z: i16,
+				pub did_vibrate: bool,
+				pub timestamp: u64,
+			}
+
+			#[derive(Debug, Clone, Copy)]
+			#[repr(C)]
+			pub struct AccelRawData {
+				pub x: i16,
+				pub y: i16,
+				pub z: i16,
+			}
+
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum AccelAxisType {
+				X = 0,
+				Y = 1,
+				Z = 2,
+			}
+
+			#[allow(clippy::pub_enum_variant_names)] // Matching the SDK.
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum AccelSamplingRate {
+				_10Hz = 10,
+				_25Hz = 25,
+				_50Hz = 50,
+				_100Hz = 100,
+			}
+
+			pub type AccelDataHandler = extern "C" fn(data: *const AccelData, num_samples: u32);
+			pub type AccelRawDataHandler =
+				extern "C" fn(data: *const AccelRawData, num_samples: u32, timestamp: u64);
+			pub type AccelTapHandler = extern "C" fn(axis: AccelAxisType, direction: i32);
+
+			extern "C" {
+				pub fn accel_service_peek(data: *mut AccelData) -> i32;
+				pub fn accel_service_set_sampling_rate(rate: AccelSamplingRate) -> i32;
+				pub fn accel_service_set_samples_per_update(num_samples: u32) -> i32;
+				pub fn accel_data_service_subscribe(
+					samples_per_update: u32,
+					handler: AccelDataHandler,
+				);
+				pub fn accel_data_service_unsubscribe();
+				pub fn accel_tap_service_subscribe(handler: AccelTapHandler);
+				pub fn accel_tap_service_unsubscribe();
+				pub fn accel_raw_data_service_subscribe(
+					samples_per_update: u32,
+					handler: AccelRawDataHandler,
+				);
+			}
+		}
+
+		pub mod compass_service {
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum CompassStatus {
+				DataInvalid = 0,
+				Calibrating,
+				Calibrated,
+			}
+
+			pub type CompassHeading = i32;
+
+			#[derive(Debug, Clone, Copy)]
+			#[repr(C)]
+			pub struct CompassHeadingData {
+				pub compass_status: CompassStatus,
+				pub magnetic_heading: CompassHeading,
+				pub true_heading: CompassHeading,
+				pub is_declination_valid: bool,
+			}
+
+			pub type CompassHeadingHandler = extern "C" fn(heading: CompassHeadingData);
+
+			extern "C" {
+				pub fn compass_service_set_heading_filter(filter: CompassHeading) -> i32;
+				pub fn compass_service_subscribe(handler: CompassHeadingHandler);
+				pub fn compass_service_unsubscribe();
+				pub fn compass_service_peek(data: *mut CompassHeadingData) -> i32;
+			}
+		}
+
+		pub mod health_service {
+			use crate::{
+				foundation::wall_time::time_start_of_today,
+				standard_c::{
+					memory::void,
+					time::{time, time_t},
+				},
+			};
+			use core::ptr;
+
+			pub type HealthValue = i32;
+
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum HealthMetric {
+				StepCount,
+				ActiveSeconds,
+				WalkedDistanceMeters,
+				SleepSeconds,
+				SleepRestfulSeconds,
+				RestingKCalories,
+				ActiveKCalories,
+				HeartRateBPM,
+				HeartRateRawBPM,
+			}
+
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum HealthAggregation {
+				Sum,
+				Avg,
+				Min,
+				Max,
+			}
+
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum HealthServiceTimeScope {
+				Once,
+				Weekly,
+				DailyWeekdayOrWeekend,
+				Daily,
+			}
+
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum HealthIterationDirection {
+				Past,
+				Future,
+			}
+
+			#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+			#[repr(C)]
+			pub enum HealthEventType {
+				SignificantUpdate = 0,
+				MovementUpdate,
+				SleepUpdate,
+				MetricAlert,
+				HeartRateUpdate,
+			}
+
+			/// Bit set of `HEALTH_SERVICE_ACCESSIBILITY_MASK_*`.
+			pub type HealthServiceAccessibilityMask = u32;
+			pub const HEALTH_SERVICE_ACCESSIBILITY_MASK_AVAILABLE: HealthServiceAccessibilityMask =
+				1 << 0;
+			pub const HEALTH_SERVICE_ACCESSIBILITY_MASK_NO_PERMISSION:
+				HealthServiceAccessibilityMask = 1 << 1;
+			pub const HEALTH_SERVICE_ACCESSIBILITY_MASK_NOT_SUPPORTED:
+				HealthServiceAccessibilityMask = 1 << 2;
+			pub const HEALTH_SERVICE_ACCESSIBILITY_MASK_NOT_AVAILABLE:
+				HealthServiceAccessibilityMask = 1 << 3;
+
+			/// Bit set of `HealthActivity` values.
+			pub type HealthActivityMask = u32;
+
+			#[derive(Debug, Clone, Copy)]
+			#[repr(C)]
+			pub struct HealthMinuteData {
+				pub steps: u8,
+				pub orientation: u8,
+				pub vmc: u16,
+				/// `is_invalid: 1` (lowest bit), `light: 3`, `padding: 4`.
+				pub bits: u8,
+				pub heart_rate_bpm: u8,
+				pub reserved: [u8; 6],
+			}
+
+			impl HealthMinuteData {
+				#[must_use]
+				pub fn is_invalid(&self) -> bool {
+					self.bits & 1 != 0
+				}
+
+				/// The `AmbientLightLevel` during this minute.
+				#[must_use]
+				pub fn light(&self) -> u8 {
+					(self.bits >> 1) & 0b111
+				}
+			}
+
+			pub type HealthActivityIteratorCB = extern "C" fn(
+				activity: HealthActivityMask,
+				time_start: time_t,
+				time_end: time_t,
+				context: *mut void,
+			) -> bool;
+
+			pub type HealthEventHandler = extern "C" fn(event: HealthEventType, context: *mut void);
+
+			extern "C" {
+				pub type HealthMetricAlert;
+
+				pub fn health_service_sum(
+					metric: HealthMetric,
+					time_start: time_t,
+					time_end: time_t,
+				) -> HealthValue;
+				pub fn health_service_peek_current_value(metric: HealthMetric) -> HealthValue;
+				pub fn health_service_aggregate_averaged(
+					metric: HealthMetric,
+					time_start: time_t,
+					time_end: time_t,
+					aggregation: HealthAggregation,
+					scope: HealthServiceTimeScope,
+				) -> HealthValue;
+				pub fn health_service_metric_accessible(
+					metric: HealthMetric,
+					time_start: time_t,
+					time_end: time_t,
+				) -> HealthServiceAccessibilityMask;
+				pub fn health_service_metric_aggregate_averaged_accessible(
+					metric: HealthMetric,
+					time_start: time_t,
+					time_end: time_t,
+					aggregation: HealthAggregation,
+					scope: HealthServiceTimeScope,
+				) -> HealthServiceAccessibilityMask;
+				pub fn health_service_any_activity_accessible(
+					activity_mask: HealthActivityMask,
+					time_start: time_t,
+					time_end: time_t,
+				) -> HealthServiceAccessibilityMask;
+				pub fn health_service_get_minute_history(
+					minute_data: *mut HealthMinuteData,
+					max_records: u32,
+					time_start: &mut time_t,
+					time_end: &mut time_t,
+				) -> u32;
+				pub fn health_service_activities_iterate(
+					activity_mask: HealthActivityMask,
+					time_start: time_t,
+					time_end: time_t,
+					direction: HealthIterationDirection,
+					callback: HealthActivityIteratorCB,
+					context: *mut void,
+				);
+				pub fn health_service_events_subscribe(
+					handler: HealthEventHandler,
+					context: *mut void,
+				) -> bool;
+				pub fn health_service_events_unsubscribe() -> bool;
+				pub fn health_service_register_metric_alert(
+					metric: HealthMetric,
+					threshold: HealthValue,
+				) -> Option<&'static mut HealthMetricAlert>;
+				pub fn health_service_cancel_metric_alert(
+					alert: &'static mut HealthMetricAlert,
+				) -> bool;
+			}
+
+			/// A macro in the SDK.
+			///
+			/// # Safety
+			///
+			/// Same as [`health_service_sum`].
+			#[must_use]
+			pub unsafe fn health_service_sum_today(metric: HealthMetric) -> HealthValue {
+				health_service_sum(metric, time_start_of_today(), time(ptr::null_mut()))
+			}
+		}
+
+		pub mod unobstructed_area_service {
+			use crate::{
+				graphics::graphics_types::GRect, standard_c::memory::void,
+				user_interface::animation::AnimationProgress,
+			};
+
+			pub type UnobstructedAreaWillChangeHandler =
+				extern "C" fn(final_unobstructed_screen_area: GRect, context: *mut void);
+			pub type UnobstructedAreaChangeHandler =
+				extern "C" fn(progress: AnimationProgress, context: *mut void);
+			pub type UnobstructedAreaDidChangeHandler = extern "C" fn(context: *mut void);
+
+			#[repr(C)]
+			pub struct UnobstructedAreaHandlers {
+				pub will_change: Option<UnobstructedAreaWillChangeHandler>,
+				pub change: Option<UnobstructedAreaChangeHandler>,
+				pub did_change: Option<UnobstructedAreaDidChangeHandler>,
+			}
+
+			extern "C" {
+				pub fn unobstructed_area_service_subscribe(
+					handlers: UnobstructedAreaHandlers,
+					context: *mut void,
+				);
+				pub fn unobstructed_area_service_unsubscribe();
+			}
+		}
+	}
+
+	pub mod i18n {
+		use crate::standard_c::memory::c_str;
+
+		extern "C" {
+			pub fn i18n_get_system_locale() -> &'static c_str;
+		}
+	}
+
+	pub mod launch_reason {
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum AppLaunchReason {
+			System,
+			User,
+			Phone,
+			Wakeup,
+			Worker,
+			QuickLaunch,
+			TimelineAction,
+			Smartstrap,
+		}
+
+		extern "C" {
+			pub fn launch_reason() -> AppLaunchReason;
+			pub fn launch_get_args() -> u32;
+		}
+	}
+
+	pub mod logging {
+		use crate::standard_c::memory::{c_str, int};
+
+		//TODO: Enum AppLogLevel
+
+		extern "C" {
+			pub fn app_log(
+				log_level: u8,
+				src_filename: &c_str,
+				src_line_number: int,
+				fmt: &c_str,
+				...
+			);
+		}
+	}
+
+	pub mod math {
+		pub const TRIG_MAX_RATIO: i32 = 0xffff;
+		pub const TRIG_MAX_ANGLE: i32 = 0x1_0000;
+
+		extern "C" {
+			pub fn sin_lookup(angle: i32) -> i32;
+			pub fn cos_lookup(angle: i32) -> i32;
+			pub fn atan2_lookup(y: i16, x: i16) -> i32;
+		}
+	}
+
+	pub mod resources {
+		use crate::standard_c::memory::void;
+
+		#[derive(Copy, Clone)]
+		#[repr(transparent)]
+		pub struct ResHandle(*const void);
+
+		extern "C" {
+			pub fn resource_get_handle(resource_id: u32) -> ResHandle;
+			pub fn resource_size(h: ResHandle) -> usize;
+			pub fn resource_load(h: ResHandle, buffer: *mut u8, max_length: usize) -> usize;
+			pub fn resource_load_byte_range(
+				h: ResHandle,
+				start_offset: u32,
+				buffer: *mut u8,
+				num_bytes: usize,
+			) -> usize;
+		}
+	}
+
+	pub mod timer {
+		use crate::standard_c::memory::void;
+
+		pub type AppTimerCallback = extern "C" fn(data: *mut void);
+
+		extern "C" {
+			pub type AppTimer;
+
+			pub fn app_timer_register(
+				timeout_ms: u32,
+				callback: AppTimerCallback,
+				callback_data: *mut void,
+			) -> Option<&'static mut AppTimer>;
+			pub fn app_timer_reschedule(timer_handle: &mut AppTimer, new_timeout_ms: u32) -> bool;
+			pub fn app_timer_cancel(timer_handle: &'static mut AppTimer);
+		}
+	}
+
+	pub mod wakeup {
+		use crate::standard_c::time::time_t;
+
+		pub type WakeupId = i32;
+		pub type WakeupHandler = extern "C" fn(wakeup_id: WakeupId, cookie: i32);
+
+		extern "C" {
+			pub fn wakeup_service_subscribe(handler: WakeupHandler);
+			pub fn wakeup_schedule(
+				timestamp: time_t,
+				cookie: i32,
+				notify_if_missed: bool,
+			) -> WakeupId;
+			pub fn wakeup_cancel(wakeup_id: WakeupId);
+			pub fn wakeup_cancel_all();
+			pub fn wakeup_get_launch_event(wakeup_id: *mut WakeupId, cookie: *mut i32) -> bool;
+			pub fn wakeup_query(wakeup_id: WakeupId, timestamp: *mut time_t) -> bool;
+		}
+	}
+
+	pub mod wall_time {
+		use crate::standard_c::{
+			memory::{c_str, int},
+			time::time_t,
+		};
+
+		pub const TIMEZONE_NAME_LENGTH: usize = 32;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum WeekDay {
+			Today = 0,
+			Sunday,
+			Monday,
+			Tuesday,
+			Wednesday,
+			Thursday,
+			Friday,
+			Saturday,
+		}
+
+		extern "C" {
+			pub fn clock_copy_time_string(buffer: &mut c_str, size: u8);
+			pub fn clock_is_24h_style() -> bool;
+			pub fn clock_to_timestamp(day: WeekDay, hour: int, minute: int) -> time_t;
+			pub fn clock_is_timezone_set() -> bool;
+			pub fn clock_get_timezone(timezone: &mut c_str, buffer_size: usize);
+			pub fn time_start_of_today() -> time_t;
+		}
+	}
+	pub mod watch_info {
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum WatchInfoModel {
+			Unknown = 0,
+			PebbleOriginal,
+			PebbleSteel,
+			PebbleTime,
+			PebbleTimeSteel,
+			PebbleTimeRound14,
+			PebbleTimeRound20,
+			Pebble2Hr,
+			Pebble2Se,
+			PebbleTime2,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum WatchInfoColor {
+			Unknown = 0,
+			Black = 1,
+			White = 2,
+			Red = 3,
+			Orange = 4,
+			Gray = 5,
+			Stainless = 6,
+			MatteBlack = 7,
+			Blue = 8,
+			Green = 9,
+			Pink = 10,
+			TimeWhite = 11,
+			TimeBlack = 12,
+			TimeRed = 13,
+			TimeSteelSilver = 14,
+			TimeSteelBlack = 15,
+			TimeSteelGold = 16,
+			TimeRoundSilver14 = 17,
+			TimeRoundBlack14 = 18,
+			TimeRoundSilver20 = 19,
+			TimeRoundBlack20 = 20,
+			TimeRoundRoseGold14 = 21,
+			Pebble2SeBlack = 24,
+			Pebble2HrBlack = 25,
+			Pebble2SeWhite = 26,
+			Pebble2HrLime = 27,
+			Pebble2HrFlame = 28,
+			Pebble2HrWhite = 29,
+			Pebble2HrAqua = 30,
+			PebbleTime2Black = 31,
+			PebbleTime2Silver = 32,
+			PebbleTime2Gold = 33,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub struct WatchInfoVersion {
+			pub major: u8,
+			pub minor: u8,
+			pub patch: u8,
+		}
+
+		extern "C" {
+			pub fn watch_info_get_model() -> WatchInfoModel;
+			pub fn watch_info_get_firmware_version() -> WatchInfoVersion;
+			pub fn watch_info_get_color() -> WatchInfoColor;
+		}
+	}
+}
+
+pub mod graphics {
+	pub mod draw_commands {
+		use super::graphics_types::{GColor8, GContext, GPoint, GSize};
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GDrawCommandType {
+			Invalid = 0,
+			Path,
+			Circle,
+			PrecisePath,
+		}
+
+		extern "C" {
+			pub type GDrawCommand;
+			pub type GDrawCommandFrame;
+			pub type GDrawCommandImage;
+			pub type GDrawCommandList;
+			pub type GDrawCommandSequence;
+
+			pub fn gdraw_command_draw(ctx: &mut GContext, command: &mut GDrawCommand);
+			pub fn gdraw_command_get_type(command: &mut GDrawCommand) -> GDrawCommandType;
+			pub fn gdraw_command_set_fill_color(command: &mut GDrawCommand, fill_color: GColor8);
+			pub fn gdraw_command_get_fill_color(command: &mut GDrawCommand) -> GColor8;
+			pub fn gdraw_command_set_stroke_color(
+				command: &mut GDrawCommand,
+				stroke_color: GColor8,
+			);
+			pub fn gdraw_command_get_stroke_color(command: &mut GDrawCommand) -> GColor8;
+			pub fn gdraw_command_set_stroke_width(command: &mut GDrawCommand, stroke_width: u8);
+			pub fn gdraw_command_get_stroke_width(command: &mut GDrawCommand) -> u8;
+			pub fn gdraw_command_get_num_points(command: &mut GDrawCommand) -> u16;
+			pub fn gdraw_command_set_point(
+				command: &mut GDrawCommand,
+				point_idx: u16,
+				point: GPoint,
+			);
+			pub fn gdraw_command_get_point(command: &mut GDrawCommand, point_idx: u16) -> GPoint;
+			pub fn gdraw_command_set_radius(command: &mut GDrawCommand, radius: u16);
+			pub fn gdraw_command_get_radius(command: &mut GDrawCommand) -> u16;
+			pub fn gdraw_command_set_path_open(command: &mut GDrawCommand, path_open: bool);
+			pub fn gdraw_command_get_path_open(command: &mut GDrawCommand) -> bool;
+			pub fn gdraw_command_set_hidden(command: &mut GDrawCommand, hidden: bool);
+			pub fn gdraw_command_get_hidden(command: &mut GDrawCommand) -> bool;
+
+			pub fn gdraw_command_frame_draw(
+				ctx: &mut GContext,
+				sequence: &mut GDrawCommandSequence,
+				frame: &mut GDrawCommandFrame,
+				offset: GPoint,
+			);
+			pub fn gdraw_command_frame_set_duration(frame: &mut GDrawCommandFrame, duration: u32);
+			pub fn gdraw_command_frame_get_duration(frame: &mut GDrawCommandFrame) -> u32;
+			pub fn gdraw_command_frame_get_command_list(
+				frame: &mut GDrawCommandFrame,
+			) -> &mut GDrawCommandList;
+
+			pub fn gdraw_command_image_create_with_resource(
+				resource_id: u32,
+			) -> Option<&'static mut GDrawCommandImage>;
+			pub fn gdraw_command_image_destroy(image: &'static mut GDrawCommandImage);
+			pub fn gdraw_command_image_draw(
+				ctx: &mut GContext,
+				image: &mut GDrawCommandImage,
+				offset: GPoint,
+			);
+			pub fn gdraw_command_image_get_bounds_size(image: &mut GDrawCommandImage) -> GSize;
+			pub fn gdraw_command_image_set_bounds_size(image: &mut GDrawCommandImage, size: GSize);
+			pub fn gdraw_command_image_get_command_list(
+				image: &mut GDrawCommandImage,
+			) -> &mut GDrawCommandList;
+
+			pub fn gdraw_command_list_draw(ctx: &mut GContext, command_list: &mut GDrawCommandList);
+			pub fn gdraw_command_list_get_command(
+				command_list: &mut GDrawCommandList,
+				command_idx: u16,
+			) -> Option<&mut GDrawCommand>;
+			pub fn gdraw_command_list_get_num_commands(command_list: &mut GDrawCommandList) -> u32;
+
+			pub fn gdraw_command_sequence_create_with_resource(
+				resource_id: u32,
+			) -> Option<&'static mut GDrawCommandSequence>;
+			pub fn gdraw_command_sequence_destroy(sequence: &'static mut GDrawCommandSequence);
+			pub fn gdraw_command_sequence_get_frame_by_elapsed(
+				sequence: &mut GDrawCommandSequence,
+				elapsed_ms: u32,
+			) -> Option<&mut GDrawCommandFrame>;
+			pub fn gdraw_command_sequence_get_frame_by_index(
+				sequence: &mut GDrawCommandSequence,
+				index: u32,
+			) -> Option<&mut GDrawCommandFrame>;
+			pub fn gdraw_command_sequence_get_bounds_size(
+				sequence: &mut GDrawCommandSequence,
+			) -> GSize;
+			pub fn gdraw_command_sequence_set_bounds_size(
+				sequence: &mut GDrawCommandSequence,
+				size: GSize,
+			);
+			pub fn gdraw_command_sequence_get_play_count(
+				sequence: &mut GDrawCommandSequence,
+			) -> u32;
+			pub fn gdraw_command_sequence_set_play_count(
+				sequence: &mut GDrawCommandSequence,
+				play_count: u32,
+			);
+			pub fn gdraw_command_sequence_get_total_duration(
+				sequence: &mut GDrawCommandSequence,
+			) -> u32;
+			pub fn gdraw_command_sequence_get_num_frames(
+				sequence: &mut GDrawCommandSequence,
+			) -> u32;
+		}
+	}
+
+	pub mod drawing_primitives {
+		use super::graphics_types::{GContext, GCornerMask, GPoint, GRect};
+
+		extern "C" {
+			pub fn graphics_draw_pixel(ctx: &mut GContext, point: GPoint);
+			pub fn graphics_draw_line(ctx: &mut GContext, p0: GPoint, p1: GPoint);
+			pub fn graphics_draw_rect(ctx: &mut GContext, rect: GRect);
+			pub fn graphics_fill_rect(
+				ctx: &mut GContext,
+				rect: GRect,
+				corner_radius: u16,
+				corner_mask: GCornerMask,
+			);
+			pub fn graphics_draw_circle(ctx: &mut GContext, p: GPoint, radius: u16);
+			pub fn graphics_fill_circle(ctx: &mut GContext, p: GPoint, radius: u16);
+			pub fn graphics_draw_round_rect(ctx: &mut GContext, rect: GRect, radius: u16);
+		}
+	}
+
+	pub mod fonts {
+		use crate::{foundation::resources::ResHandle, standard_c::memory::c_str};
+
+		extern "C" {
+			pub type FontInfo;
+
+			pub fn fonts_get_system_font(font_key: &c_str) -> &'static mut FontInfo;
+			pub fn fonts_load_custom_font(handle: ResHandle) -> Option<&'static mut FontInfo>;
+			pub fn fonts_unload_custom_font(font: &'static mut FontInfo);
+		}
+	}
+
+	pub mod graphics_context {
+		use super::graphics_types::{GColor8, GContext};
+
+		extern "C" {
+			pub fn graphics_context_set_stroke_color(ctx: &mut GContext, color: GColor8);
+			pub fn graphics_context_set_fill_color(ctx: &mut GContext, color: GColor8);
+			pub fn graphics_context_set_text_color(ctx: &mut GContext, color: GColor8);
+			pub fn graphics_context_set_antialiased(ctx: &mut GContext, enable: bool);
+			pub fn graphics_context_set_stroke_width(ctx: &mut GContext, stroke_width: u8);
+		}
+	}
+
+	pub mod graphics_types {
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub struct GPoint {
+			pub x: i16,
+			pub y: i16,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub struct GRect {
+			pub origin: GPoint,
+			pub size: GSize,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub struct GSize {
+			pub w: i16,
+			pub h: i16,
+		}
+
+		#[repr(C)]
+		pub union GColor8 {
+			pub argb: u8,
+		}
+
+		pub type GColor = GColor8;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GAlign {
+			Center = 0,
+			TopLeft,
+			TopRight,
+			Top,
+			Left,
+			Bottom,
+			Right,
+			BottomRight,
+			BottomLeft,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GCompOp {
+			Assign = 0,
+			AssignInverted,
+			Or,
+			And,
+			Clear,
+			Set,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GCornerMask {
+			GCornerNone = 0,
+			GCornerTopLeft = 1,
+			GCornerTopRight = 2,
+			GCornerBottomLeft = 4,
+			GCornerBottomRight = 8,
+			GCornersAll = 0x0f,
+			GCornersTop = 0x03,
+			GCornersBottom = 0x0c,
+			GCornersLeft = 0x05,
+			GCornersRight = 0x0a,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GBitmapFormat {
+			Format1Bit = 0,
+			Format8Bit,
+			Format1BitPalette,
+			Format2BitPalette,
+			Format4BitPalette,
+			Format8BitCircular,
+		}
+
+		#[repr(C)]
+		pub struct GBitmapDataRowInfo {
+			pub data: *mut u8,
+			pub min_x: i16,
+			pub max_x: i16,
+		}
+
+		extern "C" {
+			pub type GBitmap;
+			pub type GBitmapSequence;
+			pub type GContext;
+
+			pub fn gbitmap_create_with_resource(resource_id: u32) -> Option<&'static mut GBitmap>;
+			pub fn gbitmap_create_from_png_data(
+				png_data: *const u8,
+				png_data_size: usize,
+			) -> Option<&'static mut GBitmap>;
+			pub fn gbitmap_create_blank(
+				size: GSize,
+				format: GBitmapFormat,
+			) -> Option<&'static mut GBitmap>;
+			pub fn gbitmap_destroy(bitmap: &'static mut GBitmap);
+			pub fn gbitmap_get_bounds(bitmap: &GBitmap) -> GRect;
+			pub fn gbitmap_set_bounds(bitmap: &mut GBitmap, bounds: GRect);
+			pub fn gbitmap_get_format(bitmap: &GBitmap) -> GBitmapFormat;
+			pub fn gbitmap_get_bytes_per_row(bitmap: &GBitmap) -> u16;
+			pub fn gbitmap_get_data(bitmap: &GBitmap) -> *mut u8;
+			pub fn gbitmap_set_data(
+				bitmap: &mut GBitmap,
+				data: *mut u8,
+				format: GBitmapFormat,
+				row_size_bytes: u16,
+				free_on_destroy: bool,
+			);
+			pub fn gbitmap_get_palette(bitmap: &GBitmap) -> *mut GColor8;
+			pub fn gbitmap_set_palette(
+				bitmap: &mut GBitmap,
+				palette: *mut GColor8,
+				free_on_destroy: bool,
+			);
+			pub fn gbitmap_get_data_row_info(bitmap: &GBitmap, y: u16) -> GBitmapDataRowInfo;
+
+			pub fn gbitmap_sequence_create_with_resource(
+				resource_id: u32,
+			) -> Option<&'static mut GBitmapSequence>;
+			pub fn gbitmap_sequence_destroy(bitmap_sequence: &'static mut GBitmapSequence);
+			pub fn gbitmap_sequence_update_bitmap_next_frame(
+				bitmap_sequence: &mut GBitmapSequence,
+				bitmap: &mut GBitmap,
+				delay_ms: &mut u32,
+			) -> bool;
+			pub fn gbitmap_sequence_update_bitmap_by_elapsed(
+				bitmap_sequence: &mut GBitmapSequence,
+				bitmap: &mut GBitmap,
+				elapsed_ms: u32,
+			) -> bool;
+			pub fn gbitmap_sequence_restart(bitmap_sequence: &mut GBitmapSequence) -> bool;
+			pub fn gbitmap_sequence_get_current_frame_idx(bitmap_sequence: &GBitmapSequence)
+				-> i32;
+			pub fn gbitmap_sequence_get_total_num_frames(bitmap_sequence: &GBitmapSequence) -> u32;
+			pub fn gbitmap_sequence_get_play_count(bitmap_sequence: &GBitmapSequence) -> u32;
+			pub fn gbitmap_sequence_set_play_count(
+				bitmap_sequence: &mut GBitmapSequence,
+				play_count: u32,
+			);
+			pub fn gbitmap_sequence_get_total_duration(bitmap_sequence: &GBitmapSequence) -> u32;
+			pub fn gbitmap_sequence_get_bitmap_size(bitmap_sequence: &GBitmapSequence) -> GSize;
+		}
+
+		pub mod color_definitions {
+			use super::GColor8;
+
+			macro_rules! colors {
+				($name:ident = $value:literal $(, $further_name:ident = $further_value:literal)*$(,)?) => {
+					pub const $name: GColor8 = GColor8 {
+						argb: $value,
+					};
+					$(colors!($further_name = $further_value);)*
+				};
+			}
+
+			colors! {
+				BLUE_MOON = 0b_11_00_01_11,
+				MELON = 0b_11_11_10_10,
+				YELLOW = 0b_11_11_11_00,
+			}
+		}
+	}
+	pub mod path {
+		use super::graphics_types::{GContext, GPoint};
+
+		#[repr(C)]
+		pub struct GPathInfo {
+			pub num_points: u32,
+			pub points: *mut GPoint,
+		}
+
+		extern "C" {
+			pub type GPath;
+
+			pub fn gpath_create(init: &GPathInfo) -> Option<&'static mut GPath>;
+			pub fn gpath_destroy(gpath: &'static mut GPath);
+			pub fn gpath_draw_filled(ctx: &mut GContext, path: &mut GPath);
+			pub fn gpath_draw_outline(ctx: &mut GContext, path: &mut GPath);
+			pub fn gpath_rotate_to(path: &mut GPath, angle: i32);
+			pub fn gpath_move_to(path: &mut GPath, point: GPoint);
+			pub fn gpath_draw_outline_open(ctx: &mut GContext, path: &mut GPath);
+		}
+	}
+
+	pub mod text {
+		use super::{
+			fonts::FontInfo,
+			graphics_types::{GContext, GRect, GSize},
+		};
+		use crate::standard_c::memory::c_str;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GTextOverflowMode {
+			WordWrap = 0,
+			TrailingEllipsis,
+			Fill,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum GTextAlignment {
+			Left = 0,
+			Center,
+			Right,
+		}
+
+		extern "C" {
+			pub type GTextAttributes;
+
+			pub fn graphics_draw_text(
+				ctx: &mut GContext,
+				text: &c_str,
+				font: &FontInfo,
+				box_: GRect,
+				overflow_mode: GTextOverflowMode,
+				alignment: GTextAlignment,
+				text_attributes: Option<&mut GTextAttributes>,
+			);
+			pub fn graphics_text_layout_get_content_size(
+				text: &c_str,
+				font: &FontInfo,
+				box_: GRect,
+				overflow_mode: GTextOverflowMode,
+				alignment: GTextAlignment,
+			) -> GSize;
+		}
+	}
+}
+
+pub mod user_interface {
+	pub mod action_menu {
+		use super::window::Window;
+		use crate::{
+			graphics::graphics_types::GColor8,
+			standard_c::memory::{c_str, void},
+		};
+		use core::ptr::NonNull;
+
+		pub type ActionMenuPerformActionCb = extern "C" fn(
+			action_menu: &mut ActionMenu,
+			action: &ActionMenuItem,
+			context: *mut void,
+		);
+		pub type ActionMenuEachItemCb = extern "C" fn(item: &ActionMenuItem, context: *mut void);
+		pub type ActionMenuDidCloseCb = extern "C" fn(
+			menu: &mut ActionMenu,
+			performed_action: Option<&ActionMenuItem>,
+			context: *mut void,
+		);
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum ActionMenuAlign {
+			Top = 0,
+			Center,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum ActionMenuLevelDisplayMode {
+			Wide,
+			Thin,
+		}
+
+		#[repr(C)]
+		pub struct ActionMenuColors {
+			pub background: GColor8,
+			pub foreground: GColor8,
+		}
+
+		#[repr(C)]
+		pub struct ActionMenuConfig<'a> {
+			pub root_level: &'a ActionMenuLevel,
+			pub context: *mut void,
+			pub colors: ActionMenuColors,
+			pub will_close: Option<ActionMenuDidCloseCb>,
+			pub did_close: Option<ActionMenuDidCloseCb>,
+			pub align: ActionMenuAlign,
+		}
+
+		extern "C" {
+			pub type ActionMenuItem;
+			pub type ActionMenuLevel;
+			pub type ActionMenu;
+
+			pub fn action_menu_item_get_label(item: &ActionMenuItem) -> &c_str;
+			pub fn action_menu_item_get_action_data(item: &ActionMenuItem) -> *mut void;
+			pub fn action_menu_level_create(max_items: u16)
+				-> Option<&'static mut ActionMenuLevel>;
+			pub fn action_menu_level_set_display_mode(
+				level: &mut ActionMenuLevel,
+				display_mode: ActionMenuLevelDisplayMode,
+			);
+			pub fn action_menu_level_add_action(
+				level: &mut ActionMenuLevel,
+				label: &c_str,
+				cb: ActionMenuPerformActionCb,
+				action_data: *mut void,
+			) -> Option<NonNull<ActionMenuItem>>;
+			pub fn action_menu_level_add_child(
+				level: &mut ActionMenuLevel,
+				child: &'static mut ActionMenuLevel,
+				label: &c_str,
+			) -> Option<NonNull<ActionMenuItem>>;
+			pub fn action_menu_hierarchy_destroy(
+				root: &ActionMenuLevel,
+				each_cb: Option<ActionMenuEachItemCb>,
+				context: *mut void,
+			);
+			pub fn action_menu_get_context(action_menu: &mut ActionMenu) -> *mut void;
+			pub fn action_menu_get_root_level(
+				action_menu: &mut ActionMenu,
+			) -> NonNull<ActionMenuLevel>;
+			pub fn action_menu_open(
+				config: &mut ActionMenuConfig,
+			) -> Option<&'static mut ActionMenu>;
+			pub fn action_menu_freeze(action_menu: &mut ActionMenu);
+			pub fn action_menu_unfreeze(action_menu: &mut ActionMenu);
+			pub fn action_menu_is_frozen(action_menu: &mut ActionMenu) -> bool;
+			pub fn action_menu_set_result_window(
+				action_menu: &mut ActionMenu,
+				result_window: &mut Window,
+			);
+			pub fn action_menu_close(action_menu: &mut ActionMenu, animated: bool);
+		}
+	}
+
+	pub mod action_bar_layer {
+		use super::{
+			clicks::{ButtonId, ClickConfigProvider},
+			layers::Layer,
+			window::Window,
+		};
+		use crate::{
+			graphics::graphics_types::{GBitmap, GColor8},
+			standard_c::memory::void,
+		};
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum ActionBarLayerIconPressAnimation {
+			None = 0,
+			MoveLeft,
+			MoveUp,
+			MoveDown,
+			MoveRight,
+		}
+
+		extern "C" {
+			pub type ActionBarLayer;
+
+			pub fn action_bar_layer_create() -> Option<&'static mut ActionBarLayer>;
+			pub fn action_bar_layer_destroy(action_bar_layer: &'static mut ActionBarLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn action_bar_layer_get_layer(action_bar_layer: &ActionBarLayer) -> &mut Layer;
+
+			pub fn action_bar_layer_set_context(
+				action_bar: &mut ActionBarLayer,
+				context: *mut void,
+			);
+			pub fn action_bar_layer_set_click_config_provider(
+				action_bar: &mut ActionBarLayer,
+				click_config_provider: Option<ClickConfigProvider>,
+			);
+			pub fn action_bar_layer_set_icon(
+				action_bar: &mut ActionBarLayer,
+				button_id: ButtonId,
+				icon: &GBitmap,
+			);
+			pub fn action_bar_layer_clear_icon(
+				action_bar: &mut ActionBarLayer,
+				button_id: ButtonId,
+			);
+			pub fn action_bar_layer_add_to_window(
+				action_bar: &mut ActionBarLayer,
+				window: &mut Window,
+			);
+			pub fn action_bar_layer_remove_from_window(action_bar: &mut ActionBarLayer);
+			pub fn action_bar_layer_set_background_color(
+				action_bar: &mut ActionBarLayer,
+				background_color: GColor8,
+			);
+			pub fn action_bar_layer_set_icon_animated(
+				action_bar: &mut ActionBarLayer,
+				button_id: ButtonId,
+				icon: &GBitmap,
+				animated: bool,
+			);
+			pub fn action_bar_layer_set_icon_press_animation(
+				action_bar: &mut ActionBarLayer,
+				button_id: ButtonId,
+				animation: ActionBarLayerIconPressAnimation,
+			);
+		}
+	}
+
+	pub mod animation {
+		use super::layers::Layer;
+		use crate::{graphics::graphics_types::GRect, standard_c::memory::void};
+		use core::ptr::NonNull;
+
+		pub type AnimationProgress = i32;
+
+		pub const ANIMATION_NORMALIZED_MAX: AnimationProgress = 65535;
+		pub const ANIMATION_DURATION_INFINITE: u32 = u32::MAX;
+		pub const ANIMATION_PLAY_COUNT_INFINITE: u32 = u32::MAX;
+
+		/// The custom curve variants are left out, since they require a curve function.
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum AnimationCurve {
+			Linear = 0,
+			EaseIn = 1,
+			EaseOut = 2,
+			EaseInOut = 3,
+		}
+
+		pub type AnimationStartedHandler =
+			extern "C" fn(animation: &mut Animation, context: *mut void);
+		pub type AnimationStoppedHandler =
+			extern "C" fn(animation: &mut Animation, finished: bool, context: *mut void);
+
+		#[repr(C)]
+		pub struct AnimationHandlers {
+			pub started: Option<AnimationStartedHandler>,
+			pub stopped: Option<AnimationStoppedHandler>,
+		}
+
+		pub type AnimationSetupImplementation = extern "C" fn(animation: &mut Animation);
+		pub type AnimationUpdateImplementation =
+			extern "C" fn(animation: &mut Animation, progress: AnimationProgress);
+		pub type AnimationTeardownImplementation = extern "C" fn(animation: &mut Animation);
+
+		#[repr(C)]
+		pub struct AnimationImplementation {
+			pub setup: Option<AnimationSetupImplementation>,
+			pub update: Option<AnimationUpdateImplementation>,
+			pub teardown: Option<AnimationTeardownImplementation>,
+		}
+
+		extern "C" {
+			pub type Animation;
+			pub type PropertyAnimation;
+
+			pub fn animation_create() -> Option<&'static mut Animation>;
+			pub fn animation_destroy(animation: &'static mut Animation) -> bool;
+			pub fn animation_sequence_create_from_array(
+				animation_array: *const NonNull<Animation>,
+				array_len: u32,
+			) -> Option<&'static mut Animation>;
+			pub fn animation_spawn_create_from_array(
+				animation_array: *const NonNull<Animation>,
+				array_len: u32,
+			) -> Option<&'static mut Animation>;
+			pub fn animation_set_elapsed(animation: &mut Animation, elapsed_ms: u32) -> bool;
+			pub fn animation_get_elapsed(animation: &mut Animation, elapsed_ms: &mut i32) -> bool;
+			pub fn animation_set_reverse(animation: &mut Animation, reverse: bool) -> bool;
+			pub fn animation_get_reverse(animation: &mut Animation) -> bool;
+			pub fn animation_set_play_count(animation: &mut Animation, play_count: u32) -> bool;
+			pub fn animation_get_play_count(animation: &mut Animation) -> u32;
+			pub fn animation_set_duration(animation: &mut Animation, duration_ms: u32) -> bool;
+			pub fn animation_get_duration(
+				animation: &mut Animation,
+				include_delay: bool,
+				include_play_count: bool,
+			) -> u32;
+			pub fn animation_set_delay(animation: &mut Animation, delay_ms: u32) -> bool;
+			pub fn animation_get_delay(animation: &mut Animation) -> u32;
+			pub fn animation_set_curve(animation: &mut Animation, curve: AnimationCurve) -> bool;
+			pub fn animation_set_handlers(
+				animation: &mut Animation,
+				callbacks: AnimationHandlers,
+				context: *mut void,
+			) -> bool;
+			pub fn animation_get_context(animation: &mut Animation) -> *mut void;
+			pub fn animation_schedule(animation: &mut Animation) -> bool;
+			pub fn animation_unschedule(animation: &mut Animation) -> bool;
+			pub fn animation_unschedule_all();
+			pub fn animation_is_scheduled(animation: &mut Animation) -> bool;
+			pub fn animation_set_implementation(
+				animation: &mut Animation,
+				implementation: &'static AnimationImplementation,
+			) -> bool;
+
+			pub fn property_animation_create_layer_frame(
+				layer: NonNull<Layer>,
+				from_frame: Option<&GRect>,
+				to_frame: Option<&GRect>,
+			) -> Option<&'static mut PropertyAnimation>;
+			pub fn property_animation_destroy(property_animation: &'static mut PropertyAnimation);
+			pub fn property_animation_get_animation(
+				property_animation: &'static mut PropertyAnimation,
+			) -> &'static mut Animation;
+		}
+	}
+
+	pub mod bitmap_layer {
+		use super::layers::Layer;
+		use crate::graphics::graphics_types::{GAlign, GBitmap, GColor8, GCompOp, GRect};
+
+		extern "C" {
+			pub type BitmapLayer;
+
+			pub fn bitmap_layer_create(frame: GRect) -> Option<&'static mut BitmapLayer>;
+			pub fn bitmap_layer_destroy(bitmap_layer: &'static mut BitmapLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn bitmap_layer_get_layer(bitmap_layer: &BitmapLayer) -> &mut Layer;
+
+			pub fn bitmap_layer_get_bitmap(bitmap_layer: &BitmapLayer) -> *const GBitmap;
+			pub fn bitmap_layer_set_bitmap(
+				bitmap_layer: &mut BitmapLayer,
+				bitmap: Option<&GBitmap>,
+			);
+			pub fn bitmap_layer_set_alignment(bitmap_layer: &mut BitmapLayer, alignment: GAlign);
+			pub fn bitmap_layer_set_background_color(
+				bitmap_layer: &mut BitmapLayer,
+				color: GColor8,
+			);
+			pub fn bitmap_layer_set_compositing_mode(bitmap_layer: &mut BitmapLayer, mode: GCompOp);
+		}
+	}
+
+	pub mod rot_bitmap_layer {
+		use crate::graphics::graphics_types::{GBitmap, GColor8, GCompOp, GPoint};
+
+		extern "C" {
+			pub type RotBitmapLayer;
+
+			pub fn rot_bitmap_layer_create(bitmap: &GBitmap)
+				-> Option<&'static mut RotBitmapLayer>;
+			pub fn rot_bitmap_layer_destroy(bitmap: &'static mut RotBitmapLayer);
+			pub fn rot_bitmap_layer_set_corner_clip_color(
+				bitmap: &mut RotBitmapLayer,
+				color: GColor8,
+			);
+			pub fn rot_bitmap_layer_set_angle(bitmap: &mut RotBitmapLayer, angle: i32);
+			pub fn rot_bitmap_layer_increment_angle(bitmap: &mut RotBitmapLayer, angle_change: i32);
+			pub fn rot_bitmap_set_src_ic(bitmap: &mut RotBitmapLayer, ic: GPoint);
+			pub fn rot_bitmap_set_compositing_mode(bitmap: &mut RotBitmapLayer, mode: GCompOp);
+		}
+	}
+
+	pub mod clicks {
+		use crate::standard_c::memory::void;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum ButtonId {
+			Back,
+			Up,
+			Select,
+			Down,
+		}
+
+		#[repr(transparent)]
+		pub struct ClickRecognizerRef(*mut void);
+		pub type ClickHandler = extern "C" fn(recognizer: ClickRecognizerRef, context: *mut void);
+		pub type ClickConfigProvider = extern "C" fn(context: *mut void);
+
+		extern "C" {
+			pub fn click_number_of_clicks_counted(recognizer: ClickRecognizerRef) -> u8;
+			pub fn click_recognizer_get_button_id(recognizer: ClickRecognizerRef) -> ButtonId;
+			pub fn click_recognizer_is_repeating(recognizer: ClickRecognizerRef) -> bool;
+		}
+	}
+
+	pub mod layers {
+		use super::window::Window;
+		use crate::{
+			graphics::graphics_types::{GContext, GPoint, GRect},
+			standard_c::memory::void,
+		};
+		use core::ptr::NonNull;
+
+		pub type LayerUpdateProc = extern "C" fn(layer: NonNull<Layer>, NonNull<GContext>);
+
+		extern "C" {
+			pub type Layer;
+
+			pub fn layer_create(frame: GRect) -> *mut Layer;
+			pub fn layer_create_with_data(frame: GRect, data_size: usize) -> *mut Layer;
+			pub fn layer_destroy(layer: &'static mut Layer);
+			pub fn layer_mark_dirty(layer: NonNull<Layer>);
+			pub fn layer_set_update_proc(
+				layer: NonNull<Layer>,
+				update_proc: Option<LayerUpdateProc>, //TODO: Check if this is legal!
+			);
+			pub fn layer_set_frame(layer: NonNull<Layer>, frame: GRect);
+			pub fn layer_get_frame(layer: NonNull<Layer>) -> GRect;
+			pub fn layer_set_bounds(layer: NonNull<Layer>, bounds: GRect);
+			pub fn layer_get_bounds(layer: NonNull<Layer>) -> GRect;
+			pub fn layer_convert_point_to_screen(layer: NonNull<Layer>, point: GPoint) -> GPoint;
+			pub fn layer_convert_rect_to_screen(layer: NonNull<Layer>, rect: GRect) -> GRect;
+			pub fn layer_get_window(layer: NonNull<Layer>) -> *mut Window;
+			pub fn layer_remove_from_parent(child: NonNull<Layer>);
+			pub fn layer_remove_child_layers(parent: NonNull<Layer>);
+			pub fn layer_add_child(parent: NonNull<Layer>, child: NonNull<Layer>);
+			pub fn layer_insert_below_sibling(
+				layer_to_insert: NonNull<Layer>,
+				below_sibling_layer: NonNull<Layer>,
+			);
+			pub fn layer_insert_above_sibling(
+				layer_to_insert: NonNull<Layer>,
+				above_sibling_layer: NonNull<Layer>,
+			);
+			pub fn layer_set_hidden(layer: NonNull<Layer>, hidden: bool);
+			pub fn layer_get_hidden(layer: NonNull<Layer>) -> bool;
+			pub fn layer_set_clips(layer: NonNull<Layer>, clips: bool);
+			pub fn layer_get_clips(layer: NonNull<Layer>) -> bool;
+			pub fn layer_get_data(layer: NonNull<Layer>) -> NonNull<void>;
+			pub fn layer_get_unobstructed_bounds(layer: NonNull<Layer>) -> GRect;
+		}
+	}
+
+	pub mod light {
+		extern "C" {
+			pub fn light_enable_interaction();
+			pub fn light_enable(enable: bool);
+		}
+	}
+
+	pub mod menu_layer {
+		use super::{layers::Layer, window::Window};
+		use crate::{
+			graphics::graphics_types::{GBitmap, GColor8, GContext, GRect},
+			standard_c::memory::{c_str, void},
+		};
+		use core::ptr::NonNull;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub struct MenuIndex {
+			pub section: u16,
+			pub row: u16,
+		}
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum MenuRowAlign {
+			None,
+			Center,
+			Top,
+			Bottom,
+		}
+
+		pub type MenuLayerGetNumberOfSectionsCallback =
+			extern "C" fn(menu_layer: &mut MenuLayer, callback_context: &mut void) -> u16;
+		pub type MenuLayerGetNumberOfRowsInSectionsCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			section_index: u16,
+			callback_context: &mut void,
+		) -> u16;
+		pub type MenuLayerGetCellHeightCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			cell_index: &MenuIndex,
+			callback_context: &mut void,
+		) -> i16;
+		pub type MenuLayerGetHeaderHeightCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			section_index: u16,
+			callback_context: &mut void,
+		) -> i16;
+		pub type MenuLayerGetSeparatorHeightCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			cell_index: &MenuIndex,
+			callback_context: &mut void,
+		) -> i16;
+		pub type MenuLayerDrawRowCallback = extern "C" fn(
+			ctx: &mut GContext,
+			cell_layer: NonNull<Layer>,
+			cell_index: &MenuIndex,
+			callback_context: &mut void,
+		);
+		pub type MenuLayerDrawHeaderCallback = extern "C" fn(
+			ctx: &mut GContext,
+			cell_layer: NonNull<Layer>,
+			section_index: u16,
+			callback_context: &mut void,
+		);
+		pub type MenuLayerDrawSeparatorCallback = extern "C" fn(
+			ctx: &mut GContext,
+			cell_layer: NonNull<Layer>,
+			cell_index: &MenuIndex,
+			callback_context: &mut void,
+		);
+		pub type MenuLayerSelectCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			cell_index: &MenuIndex,
+			callback_context: &mut void,
+		);
+		pub type MenuLayerSelectionChangedCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			new_index: MenuIndex,
+			old_index: MenuIndex,
+			callback_context: &mut void,
+		);
+		pub type MenuLayerSelectionWillChangeCallback = extern "C" fn(
+			menu_layer: &mut MenuLayer,
+			new_index: &mut MenuIndex,
+			old_index: MenuIndex,
+			callback_context: &mut void,
+		);
+		pub type MenuLayerDrawBackgroundCallback = extern "C" fn(
+			ctx: &mut GContext,
+			bg_layer: NonNull<Layer>,
+			highlight: bool,
+			callback_context: &mut void,
+		);
+
+		#[repr(C)]
+		pub struct MenuLayerCallbacks {
+			pub get_num_sections: Option<MenuLayerGetNumberOfSectionsCallback>,
+			pub get_num_rows: Option<MenuLayerGetNumberOfRowsInSectionsCallback>,
+			pub get_cell_height: Option<MenuLayerGetCellHeightCallback>,
+			pub get_header_height: Option<MenuLayerGetHeaderHeightCallback>,
+			pub draw_row: Option<MenuLayerDrawRowCallback>,
+			pub draw_header: Option<MenuLayerDrawHeaderCallback>,
+			pub select_click: Option<MenuLayerSelectCallback>,
+			pub select_long_click: Option<MenuLayerSelectCallback>,
+			pub selection_changed: Option<MenuLayerSelectionChangedCallback>,
+			pub get_separator_height: Option<MenuLayerGetSeparatorHeightCallback>,
+			pub draw_separator: Option<MenuLayerDrawSeparatorCallback>,
+			pub selection_will_change: Option<MenuLayerSelectionWillChangeCallback>,
+			pub draw_background: Option<MenuLayerDrawBackgroundCallback>,
+		}
+
+		extern "C" {
+			pub type MenuLayer;
+
+			pub fn menu_layer_create(frame: GRect) -> Option<&'static mut MenuLayer>;
+			pub fn menu_layer_destroy(menu_layer: &'static mut MenuLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn menu_layer_get_layer(menu_layer: &MenuLayer) -> &mut Layer;
+
+			pub fn menu_layer_set_callbacks(
+				menu_layer: &mut MenuLayer,
+				callback_context: &mut void,
+				callbacks: MenuLayerCallbacks,
+			);
+			pub fn menu_layer_set_click_config_onto_window(
+				menu_layer: &mut MenuLayer,
+				window: &mut Window,
+			);
+			pub fn menu_layer_get_selected_index(menu_layer: &MenuLayer) -> MenuIndex;
+			pub fn menu_layer_set_selected_index(
+				menu_layer: &mut MenuLayer,
+				index: MenuIndex,
+				scroll_align: MenuRowAlign,
+				animated: bool,
+			);
+			pub fn menu_layer_set_selected_next(
+				menu_layer: &mut MenuLayer,
+				up: bool,
+				scroll_align: MenuRowAlign,
+				animated: bool,
+			);
+			pub fn menu_layer_reload_data(menu_layer: &mut MenuLayer);
+			pub fn menu_layer_set_normal_colors(
+				menu_layer: &mut MenuLayer,
+				background: GColor8,
+				foreground: GColor8,
+			);
+			pub fn menu_layer_set_highlight_colors(
+				menu_layer: &mut MenuLayer,
+				background: GColor8,
+				foreground: GColor8,
+			);
+			pub fn menu_cell_layer_is_highlighted(cell_layer: &Layer) -> bool;
+			pub fn menu_cell_basic_draw(
+				ctx: &mut GContext,
+				cell_layer: &Layer,
+				title: Option<&c_str>,
+				subtitle: Option<&c_str>,
+				icon: Option<&mut GBitmap>,
+			);
+			pub fn menu_cell_title_draw(ctx: &mut GContext, cell_layer: &Layer, title: &c_str);
+			pub fn menu_cell_basic_header_draw(
+				ctx: &mut GContext,
+				cell_layer: &Layer,
+				title: &c_str,
+			);
+		}
+	}
+
+	pub mod preferences {
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum PreferredContentSize {
+			Small = 0,
+			Medium,
+			Large,
+			ExtraLarge,
+		}
+
+		extern "C" {
+			pub fn preferred_content_size() -> PreferredContentSize;
+		}
+	}
+
+	pub mod scroll_layer {
+		use super::{
+			clicks::{ClickConfigProvider, ClickRecognizerRef},
+			layers::Layer,
+			window::Window,
+		};
+		use crate::{
+			graphics::graphics_types::{GPoint, GRect, GSize},
+			standard_c::memory::void,
+		};
+		use core::ptr::NonNull;
+
+		pub type ScrollLayerCallback =
+			extern "C" fn(scroll_layer: &mut ScrollLayer, context: *mut void);
+
+		#[repr(C)]
+		pub struct ScrollLayerCallbacks {
+			pub click_config_provider: Option<ClickConfigProvider>,
+			pub content_offset_changed_handler: Option<ScrollLayerCallback>,
+		}
+
+		extern "C" {
+			pub type ScrollLayer;
+
+			pub fn scroll_layer_create(frame: GRect) -> Option<&'static mut ScrollLayer>;
+			pub fn scroll_layer_destroy(scroll_layer: &'static mut ScrollLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn scroll_layer_get_layer(scroll_layer: &ScrollLayer) -> &mut Layer;
+
+			pub fn scroll_layer_add_child(scroll_layer: &mut ScrollLayer, child: NonNull<Layer>);
+			pub fn scroll_layer_set_click_config_onto_window(
+				scroll_layer: &mut ScrollLayer,
+				window: &mut Window,
+			);
+			pub fn scroll_layer_set_callbacks(
+				scroll_layer: &mut ScrollLayer,
+				callbacks: ScrollLayerCallbacks,
+			);
+			pub fn scroll_layer_set_context(scroll_layer: &mut ScrollLayer, context: *mut void);
+			pub fn scroll_layer_set_content_offset(
+				scroll_layer: &mut ScrollLayer,
+				offset: GPoint,
+				animated: bool,
+			);
+			pub fn scroll_layer_get_content_offset(scroll_layer: &mut ScrollLayer) -> GPoint;
+			pub fn scroll_layer_set_content_size(scroll_layer: &mut ScrollLayer, size: GSize);
+			pub fn scroll_layer_get_content_size(scroll_layer: &ScrollLayer) -> GSize;
+			pub fn scroll_layer_set_frame(scroll_layer: &mut ScrollLayer, frame: GRect);
+			pub fn scroll_layer_scroll_up_click_handler(
+				recognizer: ClickRecognizerRef,
+				context: *mut void,
+			);
+			pub fn scroll_layer_scroll_down_click_handler(
+				recognizer: ClickRecognizerRef,
+				context: *mut void,
+			);
+			pub fn scroll_layer_set_shadow_hidden(scroll_layer: &mut ScrollLayer, hidden: bool);
+			pub fn scroll_layer_get_shadow_hidden(scroll_layer: &ScrollLayer) -> bool;
+			pub fn scroll_layer_set_paging(scroll_layer: &mut ScrollLayer, paging_enabled: bool);
+			pub fn scroll_layer_get_paging(scroll_layer: &ScrollLayer) -> bool;
+		}
+	}
+
+	pub mod simple_menu_layer {
+		use super::{layers::Layer, menu_layer::MenuLayer, window::Window};
+		use crate::{
+			graphics::graphics_types::{GBitmap, GRect},
+			standard_c::memory::{c_str, void},
+		};
+		use core::ptr::NonNull;
+
+		pub type SimpleMenuLayerSelectCallback = extern "C" fn(index: i32, context: *mut void);
+
+		#[repr(C)]
+		pub struct SimpleMenuItem<'a> {
+			pub title: Option<&'a c_str>,
+			pub subtitle: Option<&'a c_str>,
+			pub icon: Option<NonNull<GBitmap>>,
+			pub callback: Option<SimpleMenuLayerSelectCallback>,
+		}
+
+		#[repr(C)]
+		pub struct SimpleMenuSection<'a> {
+			pub title: Option<&'a c_str>,
+			pub items: *const SimpleMenuItem<'a>,
+			pub num_items: u32,
+		}
+
+		extern "C" {
+			pub type SimpleMenuLayer;
+
+			pub fn simple_menu_layer_create(
+				frame: GRect,
+				window: &mut Window,
+				sections: *const SimpleMenuSection,
+				num_sections: i32,
+				callback_context: *mut void,
+			) -> Option<&'static mut SimpleMenuLayer>;
+			pub fn simple_menu_layer_destroy(menu_layer: &'static mut SimpleMenuLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn simple_menu_layer_get_layer(simple_menu: &SimpleMenuLayer) -> &mut Layer;
+
+			pub fn simple_menu_layer_get_selected_index(simple_menu: &SimpleMenuLayer) -> i32;
+			pub fn simple_menu_layer_set_selected_index(
+				simple_menu: &mut SimpleMenuLayer,
+				index: i32,
+				animated: bool,
+			);
+			pub fn simple_menu_layer_get_menu_layer(
+				simple_menu: &mut SimpleMenuLayer,
+			) -> &mut MenuLayer;
+		}
+	}
+
+	pub mod status_bar_layer {
+		use super::layers::Layer;
+		use crate::graphics::graphics_types::GColor8;
+
+		pub const STATUS_BAR_LAYER_HEIGHT: i16 = 16;
+
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		#[repr(C)]
+		pub enum StatusBarLayerSeparatorMode {
+			None = 0,
+			Dotted = 1,
+		}
+
+		extern "C" {
+			pub type StatusBarLayer;
+
+			pub fn status_bar_layer_create() -> Option<&'static mut StatusBarLayer>;
+			pub fn status_bar_layer_destroy(status_bar_layer: &'static mut StatusBarLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn status_bar_layer_get_layer(status_bar_layer: &StatusBarLayer) -> &mut Layer;
+
+			pub fn status_bar_layer_get_background_color(
+				status_bar_layer: &StatusBarLayer,
+			) -> GColor8;
+			pub fn status_bar_layer_get_foreground_color(
+				status_bar_layer: &StatusBarLayer,
+			) -> GColor8;
+			pub fn status_bar_layer_set_colors(
+				status_bar_layer: &mut StatusBarLayer,
+				background: GColor8,
+				foreground: GColor8,
+			);
+			pub fn status_bar_layer_set_separator_mode(
+				status_bar_layer: &mut StatusBarLayer,
+				mode: StatusBarLayerSeparatorMode,
+			);
+		}
+	}
+
+	pub mod text_layer {
+		use super::layers::Layer;
+		use crate::{
+			graphics::{
+				fonts::FontInfo,
+				graphics_types::{GColor8, GRect, GSize},
+				text::{GTextAlignment, GTextOverflowMode},
+			},
+			standard_c::memory::c_str,
+		};
+
+		extern "C" {
+			pub type TextLayer;
+
+			pub fn text_layer_create(frame: GRect) -> Option<&'static mut TextLayer>;
+			pub fn text_layer_destroy(text_layer: &'static mut TextLayer);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn text_layer_get_layer(text_layer: &TextLayer) -> &mut Layer;
+
+			pub fn text_layer_set_text(text_layer: &mut TextLayer, text: &c_str);
+			pub fn text_layer_get_text(text_layer: &TextLayer) -> *const c_str;
+			pub fn text_layer_set_background_color(text_layer: &mut TextLayer, color: GColor8);
+			pub fn text_layer_set_text_color(text_layer: &mut TextLayer, color: GColor8);
+			pub fn text_layer_set_overflow_mode(
+				text_layer: &mut TextLayer,
+				line_mode: GTextOverflowMode,
+			);
+			pub fn text_layer_set_font(text_layer: &mut TextLayer, font: &FontInfo);
+			pub fn text_layer_set_text_alignment(
+				text_layer: &mut TextLayer,
+				text_alignment: GTextAlignment,
+			);
+			pub fn text_layer_get_content_size(text_layer: &mut TextLayer) -> GSize;
+			pub fn text_layer_set_size(text_layer: &mut TextLayer, max_size: GSize);
+		}
+	}
+
+	pub mod vibes {
+		use core::marker::PhantomData;
+
+		#[repr(C)]
+		pub struct VibePattern<'a> {
+			/// Pointer to an array of segment durations in on(off on)*off? order, up to 10_000ms each.
+			/// There must be at least one duration!
+			pub durations: *const u32,
+			/// Length of the array.
+			pub num_segments: u32,
+			pub phantom: PhantomData<&'a u32>,
+		}
+
+		extern "C" {
+			pub fn vibes_cancel();
+			pub fn vibes_short_pulse();
+			pub fn vibes_long_pulse();
+			pub fn vibes_double_pulse();
+			pub fn vibes_enqueue_custom_pattern(pattern: VibePattern);
+		}
+	}
+
+	pub mod window {
+		use super::{
+			clicks::{ButtonId, ClickConfigProvider, ClickHandler},
+			layers::Layer,
+		};
+		use crate::{graphics::graphics_types::GColor8, standard_c::memory::void};
+		use core::ptr::NonNull;
+
+		#[repr(C)]
+		pub struct WindowHandlers {
+			pub load: Option<WindowHandler>,
+			pub appear: Option<WindowHandler>,
+			pub disappear: Option<WindowHandler>,
+			pub unload: Option<WindowHandler>,
+		}
+
+		pub type WindowHandler = extern "C" fn(window: &mut Window);
+
+		extern "C" {
+			pub type Window;
+
+			pub fn window_create() -> Option<&'static mut Window>;
+			pub fn window_destroy(window: &'static mut Window);
+			pub fn window_set_click_config_provider(
+				window: &mut Window,
+				click_config_provider: Option<ClickConfigProvider>,
+			);
+			pub fn window_set_click_config_provider_with_context(
+				window: &mut Window,
+				click_config_provider: Option<ClickConfigProvider>,
+				context: *mut void,
+			);
+			pub fn window_get_click_config_provider(window: &Window)
+				-> Option<ClickConfigProvider>;
+			pub fn window_get_click_config_context(window: &Window) -> *mut void;
+			pub fn window_set_window_handlers(window: &mut Window, handlers: WindowHandlers);
+
+			// The watch is single-threaded and everything's on the heap, so this *should* be fine.
+			pub fn window_get_root_layer(window: &Window) -> &mut Layer;
+
+			pub fn window_set_background_color(window: &mut Window, background_color: GColor8);
+			pub fn window_is_loaded(window: &mut Window) -> bool;
+			pub fn window_set_user_data(window: &mut Window, data: *mut void);
+			pub fn window_get_user_data(window: &Window) -> *mut void;
+			pub fn window_single_click_subscribe(button_id: ButtonId, handler: ClickHandler);
+			pub fn window_single_repeating_click_subscribe(
+				button_id: ButtonId,
+				repeat_interval_ms: u16,
+				handler: ClickHandler,
+			);
+			pub fn window_multi_click_subscribe(
+				button_id: ButtonId,
+				min_clicks: u8,
+				max_clicks: u8,
+				timeout: u16,
+				last_click_only: bool,
+				handler: ClickHandler,
+			);
+			pub fn window_long_click_subscribe(
+				button_id: ButtonId,
+				delay_ms: u16,
+				down_handler: ClickHandler,
+				up_handler: ClickHandler,
+			);
+			pub fn window_raw_click_subscribe(
+				button_id: ButtonId,
+				down_handler: ClickHandler,
+				up_handler: ClickHandler,
+				context: Option<NonNull<void>>,
+			);
+			pub fn window_set_click_context(button_id: ButtonId, context: *mut void);
+		}
+
+		pub mod number_window {
+			//! A ready-made window prompting the user to pick a number.
+			//!
+			//! TODO: Images
+
+			use super::Window;
+			use crate::{
+				standard_c::memory::{c_str, void},
+				ExternData,
+			};
+			use core::{
+				marker::PhantomData,
+				ops::{Deref, DerefMut},
+			};
+
+			/// [`NumberWindow`] callbacks.
+			#[repr(C)]
+			pub struct NumberWindowCallbacks {
+				/// Called as the value is incremented.
+				pub incremented: Option<NumberWindowCallback>,
+				/// Called as the value is decremented.
+				pub decremented: Option<NumberWindowCallback>,
+				/// Called as the value is confirmed, i.e. as the SELECT button is clicked.
+				pub selected: Option<NumberWindowCallback>,
+			}
+
+			/// A [`NumberWindow`] callback.
+			pub type NumberWindowCallback =
+				for<'a> extern "C" fn(number_window: &'a mut NumberWindow<'a>, context: &mut void);
+
+			/// Limited-lifetime foreign type. See [module](./index.html) documentation.  
+			/// [`Deref`] and [`DerefMut`] towards [`Window`] (but destroying it as such might leak memory).
+			///
+			/// [`Deref`]: https://doc.rust-lang.org/stable/core/ops/trait.Deref.html
+			/// [`DerefMut`]: https://doc.rust-lang.org/stable/core/ops/trait.DerefMut.html
+			/// [`Window`]: ../foreigntype.Window.html
+			#[repr(transparent)]
+			pub struct NumberWindow<'a>(PhantomData<&'a ()>, ExternData);
+
+			extern "C" {
+				pub fn number_window_create<'a>(
+					label: &'a c_str,
+					callbacks: NumberWindowCallbacks,
+					callback_context: &'static mut void,
+				) -> Option<&'a mut NumberWindow<'a>>;
+
+				pub fn number_window_destroy(number_window: &'static mut NumberWindow);
+				pub fn number_window_set_label<'a>(
+					number_window: &mut NumberWindow<'a>,
+					label: &'a c_str,
+				);
+				pub fn number_window_set_max(number_window: &mut NumberWindow, max: i32);
+				pub fn number_window_set_min(number_window: &mut NumberWindow, min: i32);
+				pub fn number_window_set_value(number_window: &mut NumberWindow, value: i32);
+				pub fn number_window_set_step_size(number_window: &mut NumberWindow, step: i32);
+				pub fn number_window_get_value(number_window: &NumberWindow) -> i32;
+				pub fn number_window_get_window<'a>(
+					number_window: &'a NumberWindow<'a>,
+				) -> &'a Window;
+				#[allow(clashing_extern_declarations)]
+				#[link_name = "number_window_get_window"]
+				pub fn number_window_get_window_mut<'a>(
+					number_window: &'a mut NumberWindow<'a>,
+				) -> &'a mut Window;
+			}
+
+			impl<'a> Deref for NumberWindow<'a> {
+				type Target = Window;
+
+				fn deref(&self) -> &Self::Target {
+					unsafe { &*(self as *const _ as *const Self::Target) }
+				}
+			}
+
+			impl<'a> DerefMut for NumberWindow<'a> {
+				fn deref_mut(&mut self) -> &mut Self::Target {
+					unsafe { &mut *(self as *mut _ as *mut Self::Target) }
+				}
+			}
+		}
+	}
+
+	pub mod window_stack {
+		use super::window::Window;
+		use core::ptr::NonNull;
+
+		extern "C" {
+			pub fn window_stack_push(window: &'static mut Window, animated: bool);
+			pub fn window_stack_pop(animated: bool) -> Option<NonNull<Window>>;
+			pub fn window_stack_pop_all(animated: bool);
+			pub fn window_stack_remove(window: &mut Window, animated: bool) -> bool;
+			pub fn window_stack_get_top_window() -> Option<NonNull<Window>>;
+			pub fn window_stack_contains_window(window: &mut Window) -> bool;
+		}
+	}
+}
+
+pub mod worker {
+	extern "C" {
+		pub fn worker_event_loop();
+		pub fn worker_launch_app();
+	}
+}
+
+pub mod standard_c {
+	pub mod prelude {
+		pub use super::memory::prelude::*;
+	}
+
+	pub mod memory {
+		#![allow(non_camel_case_types)]
+
+		use core::convert::TryFrom;
+
+		pub mod prelude {
+			pub use super::{
+				CastUncheckedExt, CastUncheckedMutExt, OptionCastUncheckedMutExt, UpcastExt,
+				UpcastMutExt,
+			};
+		}
+
+		pub type int = i32;
+
+		extern "C" {
+			pub type c_str;
+
+			/// `void` can be safely passed back across the FFI as `&void` while [`core::ffi::c_void`] cannot.
+			/// ([`c_void`] is NOT [unsized]!)
+			///
+			/// [`core::ffi::c_void`]: https://doc.rust-lang.org/stable/core/ffi/enum.c_void.html
+			/// [`c_void`]: https://doc.rust-lang.org/stable/core/ffi/enum.c_void.html
+			/// [unsized]: https://doc.rust-lang.org/stable/core/marker/trait.Sized.html
+			pub type void;
+
+			pub fn malloc(size: usize) -> Option<&'static mut void>;
+			pub fn calloc(count: usize, size: usize) -> Option<&'static mut void>;
+			pub fn realloc(ptr: *mut void, size: usize) -> Option<&'static mut void>;
+			pub fn free(ptr: &'static mut void);
+			pub fn memcmp(ptr1: &void, ptr2: &void, n: usize) -> int;
+			pub fn memcpy(dest: &mut void, src: &void, n: usize) -> *mut void;
+			pub fn memmove(dest: *mut void, src: *const void, n: usize) -> *mut void;
+			pub fn memset(dest: &mut void, c: int, n: usize) -> *mut void;
+		}
+
+		impl<'a, T> From<&'a mut T> for &'a mut void {
+			fn from(src: &'a mut T) -> Self {
+				unsafe { &mut *(src as *mut _ as *mut void) }
+			}
+		}
+
+		impl<'a, T> From<&'a T> for &'a void {
+			fn from(src: &'a T) -> Self {
+				unsafe { &*(src as *const _ as *const void) }
+			}
+		}
+
+		pub trait CastUncheckedExt<'a> {
+			/// Casts a mutable untyped heap reference ([`&void]) into a typed one.
+			///
+			/// # Safety
+			///
+			/// Horribly unsafe if T doesn't point to an **initialised** instance of T.
+			unsafe fn cast_unchecked<T>(self) -> &'a T;
+		}
+
+		pub trait CastUncheckedMutExt<'a> {
+			/// Casts a mutable untyped heap reference ([`&mut void]) into a typed one.
+			///
+			/// # Safety
+			///
+			/// Horribly unsafe if T doesn't point to an **initialised** instance of T.
+			unsafe fn cast_unchecked_mut<T>(self) -> &'a mut T;
+		}
+
+		pub trait OptionCastUncheckedMutExt<'a> {
+			/// Casts a mutable untyped heap reference ([`&mut void]) into a typed one.
+			///
+			/// # Safety
+			///
+			/// Horribly unsafe if T doesn't point to an **initialised** instance of T.
+			unsafe fn cast_unchecked_mut<T>(self) -> Option<&'a mut T>;
+		}
+
+		pub trait UpcastExt<'a> {
+			type Output;
+
+			fn upcast(self) -> Self::Output;
+		}
+
+		pub trait UpcastMutExt<'a> {
+			type Output;
+
+			fn upcast_mut(self) -> Self::Output;
+		}
+
+		impl<'a> CastUncheckedExt<'a> for &'a void {
+			unsafe fn cast_unchecked<T>(self) -> &'a T {
+				&*(self as *const _ as *const T)
+			}
+		}
+
+		impl<'a> CastUncheckedMutExt<'a> for &'a mut void {
+			unsafe fn cast_unchecked_mut<T>(self) -> &'a mut T {
+				&mut *(self as *mut _ as *mut T)
+			}
+		}
+
+		impl<'a> OptionCastUncheckedMutExt<'a> for Option<&'a mut void> {
+			unsafe fn cast_unchecked_mut<T>(self) -> Option<&'a mut T> {
+				self.map(|void_ref| &mut *(void_ref as *mut _ as *mut T))
+			}
+		}
+
+		impl<'a, T> UpcastMutExt<'a> for Option<&'a mut T> {
+			type Output = Option<&'a mut void>;
+
+			fn upcast_mut(self) -> Self::Output {
+				self.map(|t_ref| t_ref.into())
+			}
+		}
+
+		impl<'a, T> UpcastExt<'a> for &'a T {
+			type Output = &'a void;
+
+			fn upcast(self) -> Self::Output {
+				self.into()
+			}
+		}
+
+		impl<'a, T> UpcastMutExt<'a> for &'a mut T {
+			type Output = &'a mut void;
+
+			fn upcast_mut(self) -> Self::Output {
+				self.into()
+			}
+		}
+
+		impl c_str {
+			/// Interprets a zero-terminated Rust [`str`] as [`c_str`].
+			///
+			/// # Errors
+			///
+			/// Iff `text` does not end with `'\0'`.
+			pub fn ref_from_str(text: &str) -> Result<&Self, ()> {
+				match text.ends_with('\0') {
+					true => Ok(unsafe { &*(text as *const _ as *const c_str) }),
+					false => Err(()),
+				}
+			}
+		}
+
+		impl<'a> TryFrom<&'a str> for &'a c_str {
+			type Error = ();
+
+			fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+				match value.ends_with('\0') {
+					true => Ok(unsafe { &*(value as *const _ as *const c_str) }),
+					false => Err(()),
+				}
+			}
+		}
+	}
+
+	pub mod time {
+		#![allow(non_camel_case_types)]
+
+		use super::memory::{c_str, int};
+
+		pub type time_t = i32;
+
+		pub const TZ_LEN: usize = 6;
+
+		#[derive(Debug, Clone, Copy)]
+		#[repr(C)]
+		pub struct tm {
+			pub tm_sec: int,
+			pub tm_min: int,
+			pub tm_hour: int,
+			pub tm_mday: int,
+			pub tm_mon: int,
+			pub tm_year: int,
+			pub tm_wday: int,
+			pub tm_yday: int,
+			pub tm_isdst: int,
+			pub tm_gmtoff: int,
+			pub tm_zone: [u8; TZ_LEN],
+		}
+
+		extern "C" {
+			pub fn time(tloc: *mut time_t) -> time_t;
+			pub fn mktime(tb: &mut tm) -> time_t;
+			pub fn localtime(timep: &time_t) -> &'static tm;
+			pub fn gmtime(timep: &time_t) -> &'static tm;
+			pub fn strftime(s: &mut c_str, maxsize: usize, format: &c_str, tm_p: &tm) -> int;
+		}
+	}
+}
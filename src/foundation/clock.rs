@@ -0,0 +1,46 @@
+//! The watch's clock settings and time zone.
+
+use super::time::Time;
+use crate::standard_c::CStrBuf;
+use core::convert::TryFrom;
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::wall_time::{WeekDay as sysWeekDay, *};
+
+/// A day of the week, or [`WeekDay::Today`].
+pub type WeekDay = sysWeekDay;
+
+/// The buffer size that fits any time zone name, including the terminator.
+pub const TIMEZONE_NAME_LENGTH: usize = 32;
+
+/// Whether the user prefers 24-hour time.
+#[must_use]
+pub fn is_24h_style() -> bool {
+	unsafe { clock_is_24h_style() }
+}
+
+/// Whether the time zone has been set, usually by the phone app.
+#[must_use]
+pub fn timezone_is_set() -> bool {
+	unsafe { clock_is_timezone_set() }
+}
+
+/// Writes the name of the current time zone (like `"America/Chicago"`) into `buffer`.
+///
+/// A `buffer` of [`TIMEZONE_NAME_LENGTH`] fits any name. The result is empty if the time zone isn't set.
+pub fn get_timezone<const N: usize>(buffer: &mut CStrBuf<N>) {
+	unsafe { buffer.fill(|s, size| clock_get_timezone(s, size)) }
+}
+
+/// The next point in time at `hour`:`minute` local time on `day`.
+///
+/// For [`WeekDay::Today`], this may be in the past.
+#[must_use]
+pub fn to_timestamp(day: WeekDay, hour: i32, minute: i32) -> Time {
+	Time(unsafe { clock_to_timestamp(day, hour, minute) })
+}
+
+/// Writes the current time into `buffer`, formatted according to [`is_24h_style()`].
+pub fn copy_time_string<const N: usize>(buffer: &mut CStrBuf<N>) {
+	let size = u8::try_from(N).unwrap_or(u8::MAX);
+	unsafe { buffer.fill(|s, _| clock_copy_time_string(s, size)) }
+}
@@ -0,0 +1,123 @@
+//! Batched logging of fixed-size items to the phone.
+
+use crate::Handle;
+use core::{convert::TryInto, marker::PhantomData, mem::size_of};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	foundation::data_logging::{DataLoggingSession as sysDataLoggingSession, *},
+	standard_c::memory::void,
+};
+
+/// Item types that can be logged.
+///
+/// The item type and length reported to the phone follow from the implementing type,
+/// so a [`Session<T>`] can only ever log items of the size it was created with.
+pub trait DataLoggingItem: private::Sealed {
+	const ITEM_TYPE: DataLoggingItemType;
+}
+
+mod private {
+	pub trait Sealed {}
+	impl Sealed for u8 {}
+	impl Sealed for u16 {}
+	impl Sealed for u32 {}
+	impl Sealed for i8 {}
+	impl Sealed for i16 {}
+	impl Sealed for i32 {}
+	impl<const N: usize> Sealed for [u8; N] {}
+}
+
+impl DataLoggingItem for u8 {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::Uint;
+}
+
+impl DataLoggingItem for u16 {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::Uint;
+}
+
+impl DataLoggingItem for u32 {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::Uint;
+}
+
+impl DataLoggingItem for i8 {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::Int;
+}
+
+impl DataLoggingItem for i16 {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::Int;
+}
+
+impl DataLoggingItem for i32 {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::Int;
+}
+
+impl<const N: usize> DataLoggingItem for [u8; N] {
+	const ITEM_TYPE: DataLoggingItemType = DataLoggingItemType::ByteArray;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataLoggingError {
+	/// Someone else is writing to this session.
+	Busy,
+	/// There's no more space to store data on the watch.
+	Full,
+	/// The session has been closed on the phone side.
+	Closed,
+	InvalidParams,
+	Internal,
+}
+
+/// A data logging session for items of type `T`. Finished when dropped.
+pub struct Session<T: DataLoggingItem>(Handle<'static, sysDataLoggingSession>, PhantomData<T>);
+
+impl<T: DataLoggingItem> Session<T> {
+	/// Creates a new data logging session identified by `tag`.
+	///
+	/// With `resume` set, an existing session with the same tag and item type is continued instead of starting a new one.
+	///
+	/// # Errors
+	///
+	/// Iff `T` is larger than [`u16::MAX`] bytes or the session couldn't be created.
+	pub fn new(tag: u32, resume: bool) -> Result<Self, ()> {
+		let item_length = size_of::<T>().try_into().ok().ok_or(())?;
+		match unsafe { data_logging_create(tag, T::ITEM_TYPE, item_length, resume) } {
+			Some(raw_session) => Ok(Self(Handle::new(raw_session), PhantomData)),
+			None => Err(()),
+		}
+	}
+
+	/// Queues `items` for transfer to the phone.
+	///
+	/// # Errors
+	///
+	/// See [`DataLoggingError`].
+	pub fn log(&self, items: &[T]) -> Result<(), DataLoggingError> {
+		let num_items = items
+			.len()
+			.try_into()
+			.ok()
+			.ok_or(DataLoggingError::InvalidParams)?;
+		match unsafe {
+			data_logging_log(
+				self.0.as_mut_unchecked(),
+				items.as_ptr() as *const void,
+				num_items,
+			)
+		} {
+			DataLoggingResult::Success => Ok(()),
+			DataLoggingResult::Busy => Err(DataLoggingError::Busy),
+			DataLoggingResult::Full => Err(DataLoggingError::Full),
+			DataLoggingResult::Closed | DataLoggingResult::NotFound => {
+				Err(DataLoggingError::Closed)
+			}
+			DataLoggingResult::InvalidParams => Err(DataLoggingError::InvalidParams),
+			DataLoggingResult::InternalErr => Err(DataLoggingError::Internal),
+		}
+	}
+}
+
+impl<T: DataLoggingItem> Drop for Session<T> {
+	fn drop(&mut self) {
+		unsafe { data_logging_finish(self.0.duplicate().unwrap()) }
+	}
+}
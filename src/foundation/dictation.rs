@@ -0,0 +1,135 @@
+//! Voice transcription through the connected phone.
+//!
+//! Only available on watches with a microphone, see [`crate::platform::PBL_MICROPHONE`].
+
+use crate::{
+	standard_c::{CStr, Stack},
+	Box, Handle,
+};
+use core::{slice, str};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	foundation::dictation::{
+		DictationSession as sysDictationSession,
+		DictationSessionStatus as sysDictationSessionStatus, *,
+	},
+	standard_c::memory::{c_str, void},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+/// Why a dictation session ended, or [`DictationSessionStatus::Success`].
+pub type DictationSessionStatus = sysDictationSessionStatus;
+
+struct DictationHandler<'a>(Box<'a, dyn 'a + FnMut(DictationSessionStatus, Option<&CStr<Stack>>)>);
+
+/// A reusable dictation UI. Destroyed when dropped.
+pub struct DictationSession<'a>(
+	Handle<'static, sysDictationSession>,
+	*mut DictationHandler<'a>,
+);
+
+impl<'a> DictationSession<'a> {
+	/// Creates a session that calls `handler` whenever a dictation ends,
+	/// with the transcription iff the status is [`DictationSessionStatus::Success`].
+	///
+	/// `buffer_size` limits the transcription's length in bytes. With `0`, it's allocated as needed instead.
+	///
+	/// # Errors
+	///
+	/// Iff the handler can't be moved onto the heap or the session can't be created,
+	/// for example because the watch has no microphone. `handler` is returned in either case.
+	pub fn new<F: 'a + FnMut(DictationSessionStatus, Option<&CStr<Stack>>)>(
+		buffer_size: u32,
+		handler: F,
+	) -> Result<Self, F> {
+		#![allow(clippy::items_after_statements)]
+
+		let handler_wrapper = Box::leak(
+			Box::new(DictationHandler(Box::new(handler)?)).map_err(|wrapper| {
+				Box::into_inner(unsafe { Box::downcast_unchecked(wrapper.0) })
+			})?,
+		) as *mut DictationHandler<'a>;
+
+		extern "C" fn raw_status(
+			_session: &mut sysDictationSession,
+			status: DictationSessionStatus,
+			transcription: *mut c_str,
+			context: *mut void,
+		) {
+			let transcription = if transcription.is_null() {
+				None
+			} else {
+				let bytes = unsafe {
+					let start = transcription as *const u8;
+					let mut len = 0;
+					while *start.add(len) != 0 {
+						len += 1;
+					}
+					slice::from_raw_parts(start, len + 1)
+				};
+				str::from_utf8(bytes)
+					.ok()
+					.map(|str| unsafe { CStr::from_zero_terminated_unchecked(str) })
+			};
+			let handler_wrapper =
+				unsafe { context.cast::<DictationHandler>().as_mut().unsafe_unwrap() };
+			(handler_wrapper.0)(status, transcription)
+		}
+
+		//SAFETY: The handler is only dropped after the session is destroyed.
+		match unsafe {
+			dictation_session_create(buffer_size, raw_status, handler_wrapper as *mut void)
+		} {
+			Some(raw_session) => Ok(Self(Handle::new(raw_session), handler_wrapper)),
+			None => Err(Box::into_inner(unsafe {
+				Box::downcast_unchecked(
+					Box::into_inner(Box::<DictationHandler>::from_raw(&mut *handler_wrapper)).0,
+				)
+			})),
+		}
+	}
+
+	/// Whether the user is asked to confirm the transcription before it's accepted. On by default.
+	pub fn enable_confirmation(&self, is_enabled: bool) {
+		unsafe { dictation_session_enable_confirmation(self.0.as_mut_unchecked(), is_enabled) }
+	}
+
+	/// Whether errors are shown to the user before the handler is called. On by default.
+	pub fn enable_error_dialogs(&self, is_enabled: bool) {
+		unsafe { dictation_session_enable_error_dialogs(self.0.as_mut_unchecked(), is_enabled) }
+	}
+
+	/// Shows the dictation UI and starts listening.
+	///
+	/// # Errors
+	///
+	/// Iff the session couldn't be started, for example because it's already running.
+	pub fn start(&self) -> Result<(), DictationSessionStatus> {
+		match unsafe { dictation_session_start(self.0.as_mut_unchecked()) } {
+			DictationSessionStatus::Success => Ok(()),
+			status => Err(status),
+		}
+	}
+
+	/// Stops listening early and hides the UI. The handler isn't called.
+	///
+	/// # Errors
+	///
+	/// Iff the session isn't running.
+	pub fn stop(&self) -> Result<(), DictationSessionStatus> {
+		match unsafe { dictation_session_stop(self.0.as_mut_unchecked()) } {
+			DictationSessionStatus::Success => Ok(()),
+			status => Err(status),
+		}
+	}
+}
+
+impl<'a> Drop for DictationSession<'a> {
+	fn drop(&mut self) {
+		unsafe {
+			// Destroy the session, THEN drop its handler.
+			dictation_session_destroy(self.0.duplicate().unwrap());
+			Box::<DictationHandler>::from_raw(&mut *self.1);
+		}
+	}
+}
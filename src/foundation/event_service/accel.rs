@@ -0,0 +1,182 @@
+//! Accelerometer taps and sample batches.
+
+use super::SubscriptionError;
+use crate::HandlerSlot;
+use core::{marker::PhantomData, mem::MaybeUninit, slice};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::event_service::accelerometer_service::{
+	AccelData as sysAccelData, AccelRawData as sysAccelRawData, *,
+};
+
+/// One accelerometer sample, in milli-G.
+pub type AccelData = sysAccelData;
+
+/// One accelerometer sample without timestamp or vibration flag, in milli-G.
+pub type AccelRawData = sysAccelRawData;
+
+pub type AccelAxis = AccelAxisType;
+
+/// The legal accelerometer sampling rates.
+pub type SamplingRate = AccelSamplingRate;
+
+/// The direction of a tap along its [`AccelAxis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccelDirection {
+	Positive,
+	Negative,
+}
+
+impl AccelDirection {
+	fn from_raw(direction: i32) -> Self {
+		match direction {
+			i32::MIN..=-1 => AccelDirection::Negative,
+			_ => AccelDirection::Positive,
+		}
+	}
+}
+
+/// The highest number of samples that can be batched into one data update.
+pub const MAX_SAMPLES_PER_UPDATE: u32 = 25;
+
+static TAP_HANDLER: HandlerSlot<dyn FnMut(AccelAxis, AccelDirection)> = HandlerSlot::new();
+static DATA_HANDLER: HandlerSlot<dyn FnMut(&[AccelData])> = HandlerSlot::new();
+static RAW_DATA_HANDLER: HandlerSlot<dyn FnMut(&[AccelRawData], u64)> = HandlerSlot::new();
+
+/// Active tap subscription. Unsubscribes when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct TapSubscription(PhantomData<*mut ()>);
+
+/// Active (raw or regular) data subscription. Unsubscribes when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct DataSubscription(PhantomData<*mut ()>);
+
+/// Subscribes `handler` to tap events.
+///
+/// # Errors
+///
+/// Iff there's already a tap subscription or the handler can't be moved onto the heap.
+pub fn subscribe_tap<F: 'static + FnMut(AccelAxis, AccelDirection)>(
+	handler: F,
+) -> Result<TapSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_tap(axis: AccelAxisType, direction: i32) {
+		TAP_HANDLER.call(|handler| handler(axis, AccelDirection::from_raw(direction)))
+	}
+
+	TAP_HANDLER.fill(handler)?;
+	unsafe { accel_tap_service_subscribe(raw_tap) }
+	Ok(TapSubscription(PhantomData))
+}
+
+/// Subscribes `handler` to batches of `samples_per_update` accelerometer samples.
+///
+/// With `samples_per_update` set to `0`, [`peek`] can be used while the subscription is active.
+///
+/// # Errors
+///
+/// Iff `samples_per_update` is larger than [`MAX_SAMPLES_PER_UPDATE`],
+/// there's already a (raw or regular) data subscription or the handler can't be moved onto the heap.
+pub fn subscribe_data<F: 'static + FnMut(&[AccelData])>(
+	samples_per_update: u32,
+	handler: F,
+) -> Result<DataSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_data(data: *const sysAccelData, num_samples: u32) {
+		DATA_HANDLER
+			.call(|handler| handler(unsafe { slice::from_raw_parts(data, num_samples as usize) }))
+	}
+
+	if samples_per_update > MAX_SAMPLES_PER_UPDATE {
+		return Err(SubscriptionError::InvalidArgument(handler));
+	}
+	if RAW_DATA_HANDLER.is_occupied() {
+		return Err(SubscriptionError::AlreadySubscribed(handler));
+	}
+	DATA_HANDLER.fill(handler)?;
+	unsafe { accel_data_service_subscribe(samples_per_update, raw_data) }
+	Ok(DataSubscription(PhantomData))
+}
+
+/// Subscribes `handler` to batches of `samples_per_update` raw accelerometer samples.
+///
+/// The handler also receives the timestamp of the first sample in the batch, in milliseconds since the epoch.
+///
+/// # Errors
+///
+/// Iff `samples_per_update` is larger than [`MAX_SAMPLES_PER_UPDATE`],
+/// there's already a (raw or regular) data subscription or the handler can't be moved onto the heap.
+pub fn subscribe_raw_data<F: 'static + FnMut(&[AccelRawData], u64)>(
+	samples_per_update: u32,
+	handler: F,
+) -> Result<DataSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_raw_data(data: *const sysAccelRawData, num_samples: u32, timestamp: u64) {
+		RAW_DATA_HANDLER.call(|handler| {
+			handler(
+				unsafe { slice::from_raw_parts(data, num_samples as usize) },
+				timestamp,
+			)
+		})
+	}
+
+	if samples_per_update > MAX_SAMPLES_PER_UPDATE {
+		return Err(SubscriptionError::InvalidArgument(handler));
+	}
+	if DATA_HANDLER.is_occupied() {
+		return Err(SubscriptionError::AlreadySubscribed(handler));
+	}
+	RAW_DATA_HANDLER.fill(handler)?;
+	unsafe { accel_raw_data_service_subscribe(samples_per_update, raw_raw_data) }
+	Ok(DataSubscription(PhantomData))
+}
+
+/// Reads the current accelerometer state.
+///
+/// # Errors
+///
+/// Iff a data subscription with a non-zero `samples_per_update` is active.
+pub fn peek() -> Result<AccelData, ()> {
+	let mut data = MaybeUninit::uninit();
+	match unsafe { accel_service_peek(data.as_mut_ptr()) } {
+		0 => Ok(unsafe { data.assume_init() }),
+		_ => Err(()),
+	}
+}
+
+/// # Errors
+///
+/// Iff the SDK rejects the change.
+pub fn set_sampling_rate(rate: SamplingRate) -> Result<(), ()> {
+	match unsafe { accel_service_set_sampling_rate(rate) } {
+		0 => Ok(()),
+		_ => Err(()),
+	}
+}
+
+/// Changes the batch size of the active data subscription.
+///
+/// # Errors
+///
+/// Iff `samples_per_update` is larger than [`MAX_SAMPLES_PER_UPDATE`] or the SDK rejects the change.
+pub fn set_samples_per_update(samples_per_update: u32) -> Result<(), ()> {
+	if samples_per_update > MAX_SAMPLES_PER_UPDATE {
+		return Err(());
+	}
+	match unsafe { accel_service_set_samples_per_update(samples_per_update) } {
+		0 => Ok(()),
+		_ => Err(()),
+	}
+}
+
+impl Drop for TapSubscription {
+	fn drop(&mut self) {
+		unsafe { accel_tap_service_unsubscribe() }
+		drop(TAP_HANDLER.take())
+	}
+}
+
+impl Drop for DataSubscription {
+	fn drop(&mut self) {
+		// This ends both raw and regular data subscriptions.
+		unsafe { accel_data_service_unsubscribe() }
+		drop(DATA_HANDLER.take());
+		drop(RAW_DATA_HANDLER.take());
+	}
+}
@@ -0,0 +1,92 @@
+//! Compass headings and calibration state.
+
+use super::SubscriptionError;
+use crate::{foundation::math::Angle, HandlerSlot};
+use core::{marker::PhantomData, mem::MaybeUninit};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::event_service::compass_service::{
+	CompassHeadingData as sysCompassHeadingData, CompassStatus as sysCompassStatus, *,
+};
+
+/// Whether the compass is usable yet.
+///
+/// Headings are unreliable while [`CompassStatus::Calibrating`] and meaningless with [`CompassStatus::DataInvalid`].
+pub type CompassStatus = sysCompassStatus;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompassHeadingData {
+	/// The heading relative to magnetic north.
+	pub magnetic_heading: Angle,
+	/// The heading relative to true north. Only valid iff `is_declination_valid`.
+	pub true_heading: Angle,
+	pub status: CompassStatus,
+	pub is_declination_valid: bool,
+}
+
+impl From<sysCompassHeadingData> for CompassHeadingData {
+	fn from(raw: sysCompassHeadingData) -> Self {
+		Self {
+			magnetic_heading: Angle(raw.magnetic_heading),
+			true_heading: Angle(raw.true_heading),
+			status: raw.compass_status,
+			is_declination_valid: raw.is_declination_valid,
+		}
+	}
+}
+
+static HANDLER: HandlerSlot<dyn FnMut(CompassHeadingData)> = HandlerSlot::new();
+
+/// Active compass subscription. Unsubscribes when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct CompassSubscription(PhantomData<*mut ()>);
+
+/// Subscribes `handler` to heading changes larger than the [heading filter].
+///
+/// [heading filter]: ./fn.set_heading_filter.html
+///
+/// # Errors
+///
+/// Iff there's already a compass subscription or the handler can't be moved onto the heap.
+pub fn subscribe<F: 'static + FnMut(CompassHeadingData)>(
+	handler: F,
+) -> Result<CompassSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_heading(heading: sysCompassHeadingData) {
+		HANDLER.call(|handler| handler(heading.into()))
+	}
+
+	HANDLER.fill(handler)?;
+	unsafe { compass_service_subscribe(raw_heading) }
+	Ok(CompassSubscription(PhantomData))
+}
+
+/// Reads the current heading.
+///
+/// # Errors
+///
+/// Iff the compass couldn't be read.
+pub fn peek() -> Result<CompassHeadingData, ()> {
+	let mut data = MaybeUninit::uninit();
+	match unsafe { compass_service_peek(data.as_mut_ptr()) } {
+		0 => Ok(unsafe { data.assume_init() }.into()),
+		_ => Err(()),
+	}
+}
+
+/// Sets the minimum heading change that causes subscribers to be notified.
+///
+/// # Errors
+///
+/// Iff `filter` is out of range, that is outside of `0..=TRIG_MAX_ANGLE / 2`.
+pub fn set_heading_filter(filter: Angle) -> Result<(), ()> {
+	match unsafe { compass_service_set_heading_filter(filter.0) } {
+		0 => Ok(()),
+		_ => Err(()),
+	}
+}
+
+impl Drop for CompassSubscription {
+	fn drop(&mut self) {
+		unsafe { compass_service_unsubscribe() }
+		drop(HANDLER.take())
+	}
+}
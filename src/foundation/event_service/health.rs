@@ -0,0 +1,294 @@
+//! Step counts, sleep, heart rate and other health metrics.
+//!
+//! Not every watch (or user) provides every metric, so check [`metric_accessible`] before relying on a value.
+
+use super::SubscriptionError;
+use crate::{foundation::time::Time, Box, Handle, HandlerSlot};
+use core::{
+	marker::PhantomData,
+	ops::{BitOr, BitOrAssign},
+	ptr,
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	foundation::event_service::health_service::{
+		HealthAggregation as sysHealthAggregation, HealthEventType as sysHealthEventType,
+		HealthIterationDirection as sysHealthIterationDirection, HealthMetric as sysHealthMetric,
+		HealthMetricAlert as sysHealthMetricAlert, HealthMinuteData as sysHealthMinuteData,
+		HealthServiceTimeScope as sysHealthServiceTimeScope, *,
+	},
+	standard_c::{memory::void, time::time_t},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+pub type HealthValue = i32;
+
+pub type HealthMetric = sysHealthMetric;
+
+pub type HealthAggregation = sysHealthAggregation;
+
+pub type HealthServiceTimeScope = sysHealthServiceTimeScope;
+
+pub type HealthIterationDirection = sysHealthIterationDirection;
+
+pub type HealthEventType = sysHealthEventType;
+
+/// One minute of recorded health data.
+pub type HealthMinuteData = sysHealthMinuteData;
+
+/// Which parts of a health query can be answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct HealthServiceAccessibilityMask(pub u32);
+
+impl HealthServiceAccessibilityMask {
+	pub const AVAILABLE: Self = Self(HEALTH_SERVICE_ACCESSIBILITY_MASK_AVAILABLE);
+	pub const NO_PERMISSION: Self = Self(HEALTH_SERVICE_ACCESSIBILITY_MASK_NO_PERMISSION);
+	pub const NOT_SUPPORTED: Self = Self(HEALTH_SERVICE_ACCESSIBILITY_MASK_NOT_SUPPORTED);
+	pub const NOT_AVAILABLE: Self = Self(HEALTH_SERVICE_ACCESSIBILITY_MASK_NOT_AVAILABLE);
+
+	#[must_use]
+	pub fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+
+	#[must_use]
+	pub fn is_available(self) -> bool {
+		self.contains(Self::AVAILABLE)
+	}
+}
+
+/// A set of activity kinds. Also used for single activities passed to [`activities_iterate`] handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct HealthActivityMask(pub u32);
+
+impl HealthActivityMask {
+	pub const NONE: Self = Self(0);
+	pub const SLEEP: Self = Self(1 << 0);
+	pub const RESTFUL_SLEEP: Self = Self(1 << 1);
+	pub const WALK: Self = Self(1 << 2);
+	pub const RUN: Self = Self(1 << 3);
+	pub const OPEN_WORKOUT: Self = Self(1 << 4);
+	pub const ALL: Self = Self((1 << 5) - 1);
+
+	#[must_use]
+	pub fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for HealthActivityMask {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		Self(self.0 | rhs.0)
+	}
+}
+
+impl BitOrAssign for HealthActivityMask {
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0
+	}
+}
+
+/// Sums up `metric` over the given time range.
+#[must_use]
+pub fn sum(metric: HealthMetric, time_start: Time, time_end: Time) -> HealthValue {
+	unsafe { health_service_sum(metric, time_start.0, time_end.0) }
+}
+
+/// Sums up `metric` since midnight.
+#[must_use]
+pub fn sum_today(metric: HealthMetric) -> HealthValue {
+	unsafe { health_service_sum_today(metric) }
+}
+
+/// Reads the most recent value of `metric`. Only meaningful for metrics like the current heart rate.
+#[must_use]
+pub fn peek_current_value(metric: HealthMetric) -> HealthValue {
+	unsafe { health_service_peek_current_value(metric) }
+}
+
+/// Aggregates `metric` over the given time range, averaged across `scope` (e.g. all weekdays).
+#[must_use]
+pub fn aggregate_averaged(
+	metric: HealthMetric,
+	time_start: Time,
+	time_end: Time,
+	aggregation: HealthAggregation,
+	scope: HealthServiceTimeScope,
+) -> HealthValue {
+	unsafe {
+		health_service_aggregate_averaged(metric, time_start.0, time_end.0, aggregation, scope)
+	}
+}
+
+#[must_use]
+pub fn metric_accessible(
+	metric: HealthMetric,
+	time_start: Time,
+	time_end: Time,
+) -> HealthServiceAccessibilityMask {
+	HealthServiceAccessibilityMask(unsafe {
+		health_service_metric_accessible(metric, time_start.0, time_end.0)
+	})
+}
+
+#[must_use]
+pub fn metric_aggregate_averaged_accessible(
+	metric: HealthMetric,
+	time_start: Time,
+	time_end: Time,
+	aggregation: HealthAggregation,
+	scope: HealthServiceTimeScope,
+) -> HealthServiceAccessibilityMask {
+	HealthServiceAccessibilityMask(unsafe {
+		health_service_metric_aggregate_averaged_accessible(
+			metric,
+			time_start.0,
+			time_end.0,
+			aggregation,
+			scope,
+		)
+	})
+}
+
+#[must_use]
+pub fn any_activity_accessible(
+	activity_mask: HealthActivityMask,
+	time_start: Time,
+	time_end: Time,
+) -> HealthServiceAccessibilityMask {
+	HealthServiceAccessibilityMask(unsafe {
+		health_service_any_activity_accessible(activity_mask.0, time_start.0, time_end.0)
+	})
+}
+
+/// Fills `minute_data` with per-minute records, starting at `time_start`.
+///
+/// Returns the filled part of `minute_data` and the time range it actually covers,
+/// which may start later than requested if older data isn't available.
+pub fn get_minute_history(
+	minute_data: &mut [HealthMinuteData],
+	time_start: Time,
+	time_end: Time,
+) -> (&mut [HealthMinuteData], Time, Time) {
+	let mut time_start = time_start.0;
+	let mut time_end = time_end.0;
+	#[allow(clippy::cast_possible_truncation)] // Saturated below.
+	let max_records = minute_data.len().min(u32::MAX as usize) as u32;
+	let records = unsafe {
+		health_service_get_minute_history(
+			minute_data.as_mut_ptr(),
+			max_records,
+			&mut time_start,
+			&mut time_end,
+		)
+	};
+	(
+		&mut minute_data[..records as usize],
+		Time(time_start),
+		Time(time_end),
+	)
+}
+
+/// Calls `handler` for each recorded activity matching `activity_mask` in the given time range.
+///
+/// `handler` receives the activity along with its start and end time, and can return `false` to stop the iteration early.
+pub fn activities_iterate<F: FnMut(HealthActivityMask, Time, Time) -> bool>(
+	activity_mask: HealthActivityMask,
+	time_start: Time,
+	time_end: Time,
+	direction: HealthIterationDirection,
+	mut handler: F,
+) {
+	extern "C" fn raw_activity<F: FnMut(HealthActivityMask, Time, Time) -> bool>(
+		activity: u32,
+		time_start: time_t,
+		time_end: time_t,
+		context: *mut void,
+	) -> bool {
+		let handler = unsafe { &mut *(context as *mut F) };
+		handler(
+			HealthActivityMask(activity),
+			Time(time_start),
+			Time(time_end),
+		)
+	}
+
+	unsafe {
+		//SAFETY: The iteration is synchronous, so `handler` outlives all calls.
+		health_service_activities_iterate(
+			activity_mask.0,
+			time_start.0,
+			time_end.0,
+			direction,
+			raw_activity::<F>,
+			&mut handler as *mut _ as *mut void,
+		)
+	}
+}
+
+static EVENT_HANDLER: HandlerSlot<dyn FnMut(HealthEventType)> = HandlerSlot::new();
+
+/// Active health event subscription. Unsubscribes when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct HealthEventSubscription(PhantomData<*mut ()>);
+
+/// Subscribes `handler` to health events, including [metric alerts].
+///
+/// [metric alerts]: ./struct.HealthMetricAlert.html
+///
+/// # Errors
+///
+/// Iff there's already a health event subscription, the handler can't be moved onto the heap or health data isn't available.
+pub fn subscribe<F: 'static + FnMut(HealthEventType)>(
+	handler: F,
+) -> Result<HealthEventSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_event(event: sysHealthEventType, _context: *mut void) {
+		EVENT_HANDLER.call(|handler| handler(event))
+	}
+
+	EVENT_HANDLER.fill(handler)?;
+	let subscribed =
+		unsafe { health_service_events_subscribe(raw_event, ptr::null_mut::<u8>() as *mut void) };
+	match subscribed {
+		true => Ok(HealthEventSubscription(PhantomData)),
+		false => Err(SubscriptionError::Unavailable(Box::into_inner(unsafe {
+			Box::downcast_unchecked(EVENT_HANDLER.take().unsafe_unwrap())
+		}))),
+	}
+}
+
+impl Drop for HealthEventSubscription {
+	fn drop(&mut self) {
+		unsafe { health_service_events_unsubscribe() };
+		drop(EVENT_HANDLER.take())
+	}
+}
+
+/// A registered threshold for a metric. Cancelled when dropped.
+///
+/// Crossing the threshold is reported through the [event subscription] as metric alert event.
+///
+/// [event subscription]: ./fn.subscribe.html
+pub struct HealthMetricAlert(Handle<'static, sysHealthMetricAlert>);
+
+impl HealthMetricAlert {
+	/// # Errors
+	///
+	/// Iff the alert couldn't be registered, for example because `metric` doesn't support alerts.
+	pub fn register(metric: HealthMetric, threshold: HealthValue) -> Result<Self, ()> {
+		match unsafe { health_service_register_metric_alert(metric, threshold) } {
+			Some(raw_alert) => Ok(Self(Handle::new(raw_alert))),
+			None => Err(()),
+		}
+	}
+}
+
+impl Drop for HealthMetricAlert {
+	fn drop(&mut self) {
+		unsafe { health_service_cancel_metric_alert(self.0.duplicate().unwrap()) };
+	}
+}
@@ -0,0 +1,49 @@
+//! Subscriptions to system events.
+//!
+//! Each service can only have one subscriber at a time.
+//! Subscribing returns a guard that unsubscribes and drops the handler once it goes out of scope.
+
+pub mod accel;
+pub mod compass;
+pub mod health;
+#[cfg(feature = "app")]
+pub mod unobstructed_area;
+
+/// Returned when a handler couldn't be subscribed. Contains the handler that was passed in.
+pub enum SubscriptionError<F> {
+	/// There's already an active subscription to this service.
+	AlreadySubscribed(F),
+	/// The handler couldn't be moved onto the heap.
+	OutOfMemory(F),
+	/// The service isn't available on this watch.
+	Unavailable(F),
+	/// A subscription parameter was out of range.
+	InvalidArgument(F),
+}
+
+impl<F> SubscriptionError<F> {
+	/// Retrieves the handler that couldn't be subscribed.
+	#[must_use]
+	pub fn into_handler(self) -> F {
+		match self {
+			SubscriptionError::AlreadySubscribed(handler)
+			| SubscriptionError::OutOfMemory(handler)
+			| SubscriptionError::Unavailable(handler)
+			| SubscriptionError::InvalidArgument(handler) => handler,
+		}
+	}
+
+	#[cfg(feature = "app")]
+	pub(crate) fn map_handler<G>(self, map: impl FnOnce(F) -> G) -> SubscriptionError<G> {
+		match self {
+			SubscriptionError::AlreadySubscribed(handler) => {
+				SubscriptionError::AlreadySubscribed(map(handler))
+			}
+			SubscriptionError::OutOfMemory(handler) => SubscriptionError::OutOfMemory(map(handler)),
+			SubscriptionError::Unavailable(handler) => SubscriptionError::Unavailable(map(handler)),
+			SubscriptionError::InvalidArgument(handler) => {
+				SubscriptionError::InvalidArgument(map(handler))
+			}
+		}
+	}
+}
@@ -0,0 +1,131 @@
+//! Changes to the part of the screen that isn't covered by system UI, like a Timeline Quick View peeking in.
+//!
+//! Lay out against [`LayerRef::get_unobstructed_bounds`] rather than the full bounds to stay visible.
+//!
+//! [`LayerRef::get_unobstructed_bounds`]: crate::user_interface::layers::LayerRef::get_unobstructed_bounds
+
+use super::SubscriptionError;
+use crate::{
+	graphics::graphics_types::{GPoint, GRect, GSize},
+	standard_c::void,
+	user_interface::animation::AnimationProgress,
+	HandlerSlot,
+};
+use core::{marker::PhantomData, ptr};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::event_service::unobstructed_area_service::{
+	UnobstructedAreaHandlers as sysUnobstructedAreaHandlers, *,
+};
+
+/// Each handler receives the unobstructed screen area that will be reached once the change completes.
+pub struct UnobstructedAreaHandlers<W, C, D>
+where
+	W: FnMut(GRect),
+	C: FnMut(AnimationProgress, GRect),
+	D: FnMut(GRect),
+{
+	/// Called once before the area starts changing.
+	pub will_change: W,
+	/// Called for each animation step, with progress from `0` to [`ANIMATION_NORMALIZED_MAX`].
+	///
+	/// [`ANIMATION_NORMALIZED_MAX`]: crate::user_interface::animation::ANIMATION_NORMALIZED_MAX
+	pub change: C,
+	/// Called once the area has finished changing.
+	pub did_change: D,
+}
+
+trait Subscriber {
+	fn will_change(&mut self, final_area: GRect);
+	fn change(&mut self, progress: AnimationProgress);
+	fn did_change(&mut self);
+}
+
+struct SubscriberImpl<W, C, D>
+where
+	W: FnMut(GRect),
+	C: FnMut(AnimationProgress, GRect),
+	D: FnMut(GRect),
+{
+	handlers: UnobstructedAreaHandlers<W, C, D>,
+	final_area: GRect,
+}
+
+impl<W, C, D> Subscriber for SubscriberImpl<W, C, D>
+where
+	W: FnMut(GRect),
+	C: FnMut(AnimationProgress, GRect),
+	D: FnMut(GRect),
+{
+	fn will_change(&mut self, final_area: GRect) {
+		self.final_area = final_area;
+		(self.handlers.will_change)(final_area)
+	}
+
+	fn change(&mut self, progress: AnimationProgress) {
+		(self.handlers.change)(progress, self.final_area)
+	}
+
+	fn did_change(&mut self) {
+		(self.handlers.did_change)(self.final_area)
+	}
+}
+
+static HANDLERS: HandlerSlot<dyn Subscriber> = HandlerSlot::new();
+
+/// Active unobstructed area subscription. Unsubscribes when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct UnobstructedAreaSubscription(PhantomData<*mut ()>);
+
+/// Subscribes `handlers` to changes of the unobstructed area.
+///
+/// # Errors
+///
+/// Iff there's already an unobstructed area subscription or the handlers can't be moved onto the heap.
+pub fn subscribe<W, C, D>(
+	handlers: UnobstructedAreaHandlers<W, C, D>,
+) -> Result<UnobstructedAreaSubscription, SubscriptionError<UnobstructedAreaHandlers<W, C, D>>>
+where
+	W: 'static + FnMut(GRect),
+	C: 'static + FnMut(AnimationProgress, GRect),
+	D: 'static + FnMut(GRect),
+{
+	extern "C" fn raw_will_change(final_area: GRect, _context: *mut void) {
+		HANDLERS.call(|handlers| handlers.will_change(final_area))
+	}
+
+	extern "C" fn raw_change(progress: AnimationProgress, _context: *mut void) {
+		HANDLERS.call(|handlers| handlers.change(progress))
+	}
+
+	extern "C" fn raw_did_change(_context: *mut void) {
+		HANDLERS.call(|handlers| handlers.did_change())
+	}
+
+	HANDLERS
+		.fill(SubscriberImpl {
+			handlers,
+			final_area: GRect {
+				origin: GPoint { x: 0, y: 0 },
+				size: GSize { w: 0, h: 0 },
+			},
+		})
+		.map_err(|error| error.map_handler(|subscriber| subscriber.handlers))?;
+	unsafe {
+		unobstructed_area_service_subscribe(
+			sysUnobstructedAreaHandlers {
+				will_change: Some(raw_will_change),
+				change: Some(raw_change),
+				did_change: Some(raw_did_change),
+			},
+			ptr::null_mut::<u8>() as *mut void,
+		)
+	}
+	Ok(UnobstructedAreaSubscription(PhantomData))
+}
+
+impl Drop for UnobstructedAreaSubscription {
+	fn drop(&mut self) {
+		unsafe { unobstructed_area_service_unsubscribe() }
+		drop(HANDLERS.take())
+	}
+}
@@ -0,0 +1,60 @@
+//! The watch's language setting.
+
+use core::{slice, str};
+use pebble_sys::foundation::i18n::i18n_get_system_locale;
+
+/// A language the watch's firmware can be set to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+	EnUs,
+	FrFr,
+	DeDe,
+	EsEs,
+	ItIt,
+	PtPt,
+	/// A locale this crate doesn't know about, with its ISO code like `"en_US"`.
+	Other(&'static str),
+}
+
+impl Locale {
+	/// The ISO locale code, like `"en_US"`.
+	#[must_use]
+	pub fn code(self) -> &'static str {
+		match self {
+			Locale::EnUs => "en_US",
+			Locale::FrFr => "fr_FR",
+			Locale::DeDe => "de_DE",
+			Locale::EsEs => "es_ES",
+			Locale::ItIt => "it_IT",
+			Locale::PtPt => "pt_PT",
+			Locale::Other(code) => code,
+		}
+	}
+
+	fn from_code(code: &'static str) -> Self {
+		match code {
+			"en_US" => Locale::EnUs,
+			"fr_FR" => Locale::FrFr,
+			"de_DE" => Locale::DeDe,
+			"es_ES" => Locale::EsEs,
+			"it_IT" => Locale::ItIt,
+			"pt_PT" => Locale::PtPt,
+			code => Locale::Other(code),
+		}
+	}
+}
+
+/// The language the watch is set to.
+#[must_use]
+pub fn system_locale() -> Locale {
+	let code = unsafe {
+		let start = i18n_get_system_locale() as *const _ as *const u8;
+		let mut len = 0;
+		while *start.add(len) != 0 {
+			len += 1;
+		}
+		slice::from_raw_parts(start, len)
+	};
+	// The firmware only reports ASCII codes, but don't rely on that.
+	Locale::from_code(str::from_utf8(code).unwrap_or(""))
+}
@@ -0,0 +1,96 @@
+//! Fixed-point angle and trigonometry helpers matching the SDK's conventions.
+//!
+//! With the `"reference-trig"` feature, the trigonometric functions are computed in Rust instead of through the SDK's lookup tables.
+//! The results reproduce the SDK's lookup tables, which `tests/reference_trig.rs` checks against known outputs on the host.
+
+use core::f32::consts::PI;
+#[cfg(not(feature = "reference-trig"))]
+use pebble_sys::foundation::math::{atan2_lookup, cos_lookup, sin_lookup};
+use pebble_sys::graphics::graphics_types::GPoint;
+
+/// One full turn, in [`Angle`] units.
+pub const TRIG_MAX_ANGLE: i32 = 0x1_0000;
+
+/// The fixed-point representation of `1.0` for the results of [`Angle::sin`] and [`Angle::cos`].
+///
+/// [`Angle::sin`]: ./struct.Angle.html#method.sin
+/// [`Angle::cos`]: ./struct.Angle.html#method.cos
+pub const TRIG_MAX_RATIO: i32 = 0xffff;
+
+/// An angle in fractions of [`TRIG_MAX_ANGLE`], as used throughout the SDK.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Angle(pub i32);
+
+/// The name [`Angle`] was originally introduced under.
+pub type TrigAngle = Angle;
+
+impl Angle {
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // Smaller or equal magnitude.
+	pub fn from_degrees(degrees: i32) -> Self {
+		Self((i64::from(degrees) * i64::from(TRIG_MAX_ANGLE) / 360) as i32)
+	}
+
+	/// Converts this angle to whole degrees, rounding towards zero.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // Smaller magnitude.
+	pub fn to_degrees(self) -> i32 {
+		(i64::from(self.0) * 360 / i64::from(TRIG_MAX_ANGLE)) as i32
+	}
+
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	#[allow(clippy::cast_precision_loss)]
+	pub fn from_radians(radians: f32) -> Self {
+		Self((radians * TRIG_MAX_ANGLE as f32 / (2. * PI)) as i32)
+	}
+
+	#[must_use]
+	#[allow(clippy::cast_precision_loss)]
+	pub fn to_radians(self) -> f32 {
+		self.0 as f32 * (2. * PI) / TRIG_MAX_ANGLE as f32
+	}
+
+	/// The sine of this angle, in fractions of [`TRIG_MAX_RATIO`].
+	#[must_use]
+	pub fn sin(self) -> i32 {
+		#[cfg(not(feature = "reference-trig"))]
+		return unsafe { sin_lookup(self.0) };
+		#[cfg(feature = "reference-trig")]
+		return reference::sin(self.0);
+	}
+
+	/// The cosine of this angle, in fractions of [`TRIG_MAX_RATIO`].
+	#[must_use]
+	pub fn cos(self) -> i32 {
+		#[cfg(not(feature = "reference-trig"))]
+		return unsafe { cos_lookup(self.0) };
+		#[cfg(feature = "reference-trig")]
+		return reference::cos(self.0);
+	}
+
+	/// The angle of the vector (`x`, `y`), counterclockwise from the positive x axis and between `0` and [`TRIG_MAX_ANGLE`].
+	#[must_use]
+	pub fn atan2(y: i16, x: i16) -> Self {
+		#[cfg(not(feature = "reference-trig"))]
+		return Self(unsafe { atan2_lookup(y, x) });
+		#[cfg(feature = "reference-trig")]
+		return Self(reference::atan2(y, x));
+	}
+}
+
+/// The point `radius` away from `center` in the direction of `angle`,
+/// which is measured clockwise from 12 o'clock like the hands of a watch.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // Only for very large radii.
+pub fn polar_to_point(center: GPoint, radius: i32, angle: Angle) -> GPoint {
+	let scale = |ratio: i32| i64::from(ratio) * i64::from(radius) / i64::from(TRIG_MAX_RATIO);
+	GPoint {
+		x: (i64::from(center.x) + scale(angle.sin())) as i16,
+		y: (i64::from(center.y) - scale(angle.cos())) as i16,
+	}
+}
+
+#[cfg(feature = "reference-trig")]
+mod reference;
@@ -0,0 +1,97 @@
+//! Pure-Rust versions of the SDK's `sin_lookup`, `cos_lookup` and `atan2_lookup`.
+//!
+//! This module doesn't depend on the rest of the crate (aside from two constants in `super`),
+//! so `tests/reference_trig.rs` can include it and run on the host.
+
+use super::{TRIG_MAX_ANGLE, TRIG_MAX_RATIO};
+use core::f64::consts::PI;
+
+const QUARTER: i32 = TRIG_MAX_ANGLE / 4;
+
+/// Rounds a non-negative `value`.
+#[allow(clippy::cast_possible_truncation)]
+fn round(value: f64) -> i32 {
+	(value + 0.5) as i32
+}
+
+/// Entry `index` of the SDK's quarter-wave sine table, for `index` in `0..=QUARTER`.
+///
+/// The table holds `sin` of each angle step, scaled by [`TRIG_MAX_RATIO`] and rounded to the nearest integer.
+fn sin_table(index: i32) -> i32 {
+	// Taylor series on [0, π/2], precise far beyond the fixed-point resolution.
+	let t = f64::from(index) * (2. * PI) / f64::from(TRIG_MAX_ANGLE);
+	let mut term = t;
+	let mut sum = t;
+	for n in 1..10 {
+		term *= -t * t / f64::from(2 * n * (2 * n + 1));
+		sum += term;
+	}
+	round(sum * f64::from(TRIG_MAX_RATIO))
+}
+
+/// Like the SDK's `sin_lookup`, which folds `angle` into the first quadrant before looking it up.
+pub fn sin(angle: i32) -> i32 {
+	let negative = angle < 0;
+	#[allow(clippy::cast_possible_truncation)] // Less than `TRIG_MAX_ANGLE`.
+	let angle = (i64::from(angle).abs() % i64::from(TRIG_MAX_ANGLE)) as i32;
+	let (angle, negative) = if angle >= 2 * QUARTER {
+		(angle - 2 * QUARTER, !negative)
+	} else {
+		(angle, negative)
+	};
+	let angle = if angle > QUARTER {
+		2 * QUARTER - angle
+	} else {
+		angle
+	};
+
+	let ratio = sin_table(angle);
+	if negative {
+		-ratio
+	} else {
+		ratio
+	}
+}
+
+/// Like the SDK's `cos_lookup`, which looks up the sine a quarter turn further.
+pub fn cos(angle: i32) -> i32 {
+	// Wrapping keeps the angle modulo `TRIG_MAX_ANGLE`, which divides 2³².
+	sin(angle.wrapping_add(QUARTER))
+}
+
+/// The arctangent of `z` in `[0, 1]`, in radians.
+fn atan_unit(z: f64) -> f64 {
+	// atan(z) = π/4 + atan((z - 1) / (z + 1)) keeps the series argument below tan(π/8).
+	let (offset, z) = if z > 0.414_213_562_373_095 {
+		(PI / 4., (z - 1.) / (z + 1.))
+	} else {
+		(0., z)
+	};
+	let mut power = z;
+	let mut sum = z;
+	for n in 1..20 {
+		power *= -z * z;
+		sum += power / f64::from(2 * n + 1);
+	}
+	offset + sum
+}
+
+/// Like the SDK's `atan2_lookup`, the angle of (`x`, `y`) rounded to the nearest step in `0..TRIG_MAX_ANGLE`.
+pub fn atan2(y: i16, x: i16) -> i32 {
+	if x == 0 && y == 0 {
+		return 0;
+	}
+	let (abs_y, abs_x) = (f64::from(i32::from(y).abs()), f64::from(i32::from(x).abs()));
+	let first_quadrant = if abs_y <= abs_x {
+		atan_unit(abs_y / abs_x)
+	} else {
+		PI / 2. - atan_unit(abs_x / abs_y)
+	};
+	let radians = match (x < 0, y < 0) {
+		(false, false) => first_quadrant,
+		(true, false) => PI - first_quadrant,
+		(true, true) => PI + first_quadrant,
+		(false, true) => 2. * PI - first_quadrant,
+	};
+	round(radians * f64::from(TRIG_MAX_ANGLE) / (2. * PI)) % TRIG_MAX_ANGLE
+}
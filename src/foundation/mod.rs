@@ -1,3 +1,40 @@
+#[cfg(feature = "app")]
+use pebble_sys::foundation::launch_reason::{
+	launch_get_args as sys_launch_get_args, launch_reason as sys_launch_reason,
+	AppLaunchReason as sysAppLaunchReason,
+};
+
+#[cfg(feature = "app")]
 pub mod app;
+pub mod clock;
+pub mod data_logging;
+#[cfg(feature = "app")]
+pub mod dictation;
+pub mod event_service;
+pub mod i18n;
 pub mod logging;
+pub mod math;
 pub mod resources;
+pub mod status_code;
+pub mod time;
+#[cfg(feature = "app")]
+pub mod wakeup;
+pub mod watch_info;
+pub mod worker;
+
+/// Why the app was started.
+#[cfg(feature = "app")]
+pub type AppLaunchReason = sysAppLaunchReason;
+
+#[cfg(feature = "app")]
+#[must_use]
+pub fn launch_reason() -> AppLaunchReason {
+	unsafe { sys_launch_reason() }
+}
+
+/// The argument passed along with [`AppLaunchReason::TimelineAction`].
+#[cfg(feature = "app")]
+#[must_use]
+pub fn launch_get_args() -> u32 {
+	unsafe { sys_launch_get_args() }
+}
@@ -0,0 +1,17 @@
+//! The SDK's generic status codes, as returned by several functions as plain `i32`.
+
+/// A status code. Negative values are errors.
+pub type StatusCode = i32;
+
+pub const S_SUCCESS: StatusCode = 0;
+pub const E_ERROR: StatusCode = -1;
+pub const E_UNKNOWN: StatusCode = -2;
+pub const E_INTERNAL: StatusCode = -3;
+pub const E_INVALID_ARGUMENT: StatusCode = -4;
+pub const E_OUT_OF_MEMORY: StatusCode = -5;
+pub const E_OUT_OF_STORAGE: StatusCode = -6;
+pub const E_OUT_OF_RESOURCES: StatusCode = -7;
+pub const E_RANGE: StatusCode = -8;
+pub const E_DOES_NOT_EXIST: StatusCode = -9;
+pub const E_INVALID_OPERATION: StatusCode = -10;
+pub const E_BUSY: StatusCode = -11;
@@ -0,0 +1,170 @@
+//! Points in time, as seconds since the Unix epoch, and their calendar representation.
+
+use crate::standard_c::{CStr, CStrBuf, Storage};
+use core::{
+	ops::{Add, Sub},
+	ptr,
+};
+use pebble_sys::{
+	foundation::wall_time::time_start_of_today,
+	standard_c::time::{gmtime, localtime, mktime, strftime as sys_strftime, time, time_t, tm},
+};
+
+/// A point in time, in seconds since the Unix epoch.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Time(pub time_t);
+
+impl Time {
+	#[must_use]
+	pub fn now() -> Self {
+		Self(unsafe { time(ptr::null_mut()) })
+	}
+
+	/// Midnight at the start of the current day, in local time.
+	#[must_use]
+	pub fn start_of_today() -> Self {
+		Self(unsafe { time_start_of_today() })
+	}
+}
+
+/// Offsets by a number of seconds.
+impl Add<time_t> for Time {
+	type Output = Self;
+
+	fn add(self, seconds: time_t) -> Self::Output {
+		Self(self.0 + seconds)
+	}
+}
+
+/// Offsets by a number of seconds.
+impl Sub<time_t> for Time {
+	type Output = Self;
+
+	fn sub(self, seconds: time_t) -> Self::Output {
+		Self(self.0 - seconds)
+	}
+}
+
+/// The difference in seconds.
+impl Sub for Time {
+	type Output = time_t;
+
+	fn sub(self, other: Self) -> Self::Output {
+		self.0 - other.0
+	}
+}
+
+/// A calendar date and time of day, broken down into fields.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Tm(tm);
+
+impl Tm {
+	/// `time` in the watch's local time zone.
+	#[must_use]
+	pub fn local(time: Time) -> Self {
+		Self(*unsafe { localtime(&time.0) })
+	}
+
+	/// `time` in UTC.
+	#[must_use]
+	pub fn utc(time: Time) -> Self {
+		Self(*unsafe { gmtime(&time.0) })
+	}
+
+	/// Converts back to a point in time, interpreting the fields as local time.
+	#[must_use]
+	pub fn to_time(&self) -> Time {
+		let mut tm = self.0;
+		Time(unsafe { mktime(&mut tm) })
+	}
+
+	/// `0` to `59` (`60` during leap seconds).
+	#[must_use]
+	pub fn second(&self) -> i32 {
+		self.0.tm_sec
+	}
+
+	/// `0` to `59`.
+	#[must_use]
+	pub fn minute(&self) -> i32 {
+		self.0.tm_min
+	}
+
+	/// `0` to `23`.
+	#[must_use]
+	pub fn hour(&self) -> i32 {
+		self.0.tm_hour
+	}
+
+	/// `1` to `31`.
+	#[must_use]
+	pub fn day_of_month(&self) -> i32 {
+		self.0.tm_mday
+	}
+
+	/// `0` (January) to `11` (December).
+	#[must_use]
+	pub fn month(&self) -> i32 {
+		self.0.tm_mon
+	}
+
+	/// The full year, for example `2020`.
+	#[must_use]
+	pub fn year(&self) -> i32 {
+		self.0.tm_year + 1900
+	}
+
+	/// `0` (Sunday) to `6` (Saturday).
+	#[must_use]
+	pub fn weekday(&self) -> i32 {
+		self.0.tm_wday
+	}
+
+	/// `0` to `365`.
+	#[must_use]
+	pub fn day_of_year(&self) -> i32 {
+		self.0.tm_yday
+	}
+
+	/// Whether daylight saving time is in effect.
+	#[must_use]
+	pub fn is_dst(&self) -> bool {
+		self.0.tm_isdst > 0
+	}
+
+	/// The offset from UTC, in seconds.
+	#[must_use]
+	pub fn utc_offset(&self) -> i32 {
+		self.0.tm_gmtoff
+	}
+}
+
+/// Formats `tm` according to `format` into `buffer`, like C's `strftime`.
+///
+/// Day and month names follow the watch's language.
+///
+/// # Errors
+///
+/// Iff the result doesn't fit into `buffer` or is empty, in which case `buffer` is left empty.
+pub fn strftime<const N: usize>(
+	buffer: &mut CStrBuf<N>,
+	format: &CStr<impl Storage>,
+	tm: &Tm,
+) -> Result<(), ()> {
+	let mut written = 0;
+	unsafe {
+		buffer.fill(|s, max_size| {
+			written = sys_strftime(s, max_size, format.as_c_str(), &tm.0);
+			if written == 0 {
+				//SAFETY: `max_size` is at least 1.
+				*(s as *mut _ as *mut u8) = 0;
+			}
+		})
+	}
+	match written {
+		0 => Err(()),
+		_ => Ok(()),
+	}
+}
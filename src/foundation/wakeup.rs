@@ -0,0 +1,111 @@
+//! Scheduling the app to be launched (or notified) at a later time.
+
+use super::{
+	event_service::SubscriptionError,
+	launch_reason,
+	status_code::{E_INVALID_ARGUMENT, E_OUT_OF_RESOURCES, E_RANGE},
+	time::Time,
+	AppLaunchReason,
+};
+use crate::HandlerSlot;
+use core::{marker::PhantomData, mem::MaybeUninit};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::wakeup::*;
+
+/// Identifies a scheduled wakeup event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct WakeupId(pub i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WakeupError {
+	/// Another wakeup event is scheduled within a minute of the requested time.
+	TooCloseToOther,
+	/// The requested time lies in the past.
+	InPast,
+	/// This app already has the maximum number of (8) wakeup events scheduled.
+	OutOfResources,
+	Internal,
+}
+
+/// Schedules a wakeup event at `at`, which will carry `cookie` as payload.
+///
+/// If the app is running at that time, the [subscribed](./fn.subscribe.html) handler is called.
+/// Otherwise, the app is launched with [`AppLaunchReason::Wakeup`](../type.AppLaunchReason.html).
+///
+/// # Errors
+///
+/// See [`WakeupError`].
+pub fn schedule(at: Time, cookie: i32, notify_if_missed: bool) -> Result<WakeupId, WakeupError> {
+	match unsafe { wakeup_schedule(at.0, cookie, notify_if_missed) } {
+		id if id >= 0 => Ok(WakeupId(id)),
+		E_RANGE => Err(WakeupError::TooCloseToOther),
+		E_INVALID_ARGUMENT => Err(WakeupError::InPast),
+		E_OUT_OF_RESOURCES => Err(WakeupError::OutOfResources),
+		_ => Err(WakeupError::Internal),
+	}
+}
+
+pub fn cancel(wakeup_id: WakeupId) {
+	unsafe { wakeup_cancel(wakeup_id.0) }
+}
+
+pub fn cancel_all() {
+	unsafe { wakeup_cancel_all() }
+}
+
+/// Retrieves the scheduled time of a wakeup event, iff it's still scheduled.
+#[must_use]
+pub fn query(wakeup_id: WakeupId) -> Option<Time> {
+	let mut timestamp = MaybeUninit::uninit();
+	match unsafe { wakeup_query(wakeup_id.0, timestamp.as_mut_ptr()) } {
+		true => Some(Time(unsafe { timestamp.assume_init() })),
+		false => None,
+	}
+}
+
+/// Retrieves the ID and cookie of the wakeup event that launched the app, iff it was launched that way.
+#[must_use]
+pub fn get_launch_event() -> Option<(WakeupId, i32)> {
+	if !matches!(launch_reason(), AppLaunchReason::Wakeup) {
+		return None;
+	}
+	let mut wakeup_id = MaybeUninit::uninit();
+	let mut cookie = MaybeUninit::uninit();
+	unsafe {
+		match wakeup_get_launch_event(wakeup_id.as_mut_ptr(), cookie.as_mut_ptr()) {
+			true => Some((WakeupId(wakeup_id.assume_init()), cookie.assume_init())),
+			false => None,
+		}
+	}
+}
+
+static HANDLER: HandlerSlot<dyn FnMut(WakeupId, i32)> = HandlerSlot::new();
+
+/// Active wakeup subscription. Stops forwarding wakeup events to the handler when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct WakeupSubscription(PhantomData<*mut ()>);
+
+/// Subscribes `handler` to wakeup events that occur while the app is running.
+///
+/// # Errors
+///
+/// Iff there's already a wakeup subscription or the handler can't be moved onto the heap.
+pub fn subscribe<F: 'static + FnMut(WakeupId, i32)>(
+	handler: F,
+) -> Result<WakeupSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_wakeup(wakeup_id: i32, cookie: i32) {
+		HANDLER.call(|handler| handler(WakeupId(wakeup_id), cookie))
+	}
+
+	HANDLER.fill(handler)?;
+	unsafe { wakeup_service_subscribe(raw_wakeup) }
+	Ok(WakeupSubscription(PhantomData))
+}
+
+impl Drop for WakeupSubscription {
+	fn drop(&mut self) {
+		// There's no way to unsubscribe, so the raw handler stays registered and does nothing once the slot is empty.
+		drop(HANDLER.take())
+	}
+}
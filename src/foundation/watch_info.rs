@@ -0,0 +1,34 @@
+//! Which watch the app is running on.
+//!
+//! To pick code paths at compile time instead, see [`crate::platform`].
+
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::watch_info::{
+	WatchInfoColor as sysWatchInfoColor, WatchInfoModel as sysWatchInfoModel,
+	WatchInfoVersion as sysWatchInfoVersion, *,
+};
+
+/// The hardware model, like [`WatchInfoModel::PebbleTimeRound14`].
+pub type WatchInfoModel = sysWatchInfoModel;
+
+/// The color (and material) of the watch's case.
+pub type WatchInfoColor = sysWatchInfoColor;
+
+/// A firmware version, with `major`, `minor` and `patch` fields.
+pub type WatchInfoVersion = sysWatchInfoVersion;
+
+#[must_use]
+pub fn model() -> WatchInfoModel {
+	unsafe { watch_info_get_model() }
+}
+
+#[must_use]
+pub fn color() -> WatchInfoColor {
+	unsafe { watch_info_get_color() }
+}
+
+/// The version of the firmware the app is running on.
+#[must_use]
+pub fn firmware_version() -> WatchInfoVersion {
+	unsafe { watch_info_get_firmware_version() }
+}
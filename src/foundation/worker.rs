@@ -0,0 +1,99 @@
+//! Controlling and talking to the app's background worker.
+//!
+//! Messaging works in both directions, so it's also available from [`crate::worker`].
+
+use super::event_service::SubscriptionError;
+use crate::{Box, HandlerSlot};
+use core::marker::PhantomData;
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::foundation::app_worker::{AppWorkerMessage as sysAppWorkerMessage, *};
+use unsafe_unwrap::UnsafeUnwrap;
+
+/// Three 16-bit values sent between app and worker.
+pub type AppWorkerMessage = sysAppWorkerMessage;
+
+#[cfg(feature = "app")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppWorkerError {
+	/// This app doesn't have a worker.
+	NoWorker,
+	/// Another app's worker is running.
+	DifferentApp,
+	NotRunning,
+	AlreadyRunning,
+	/// The user is being asked whether to replace another app's worker.
+	AskingConfirmation,
+}
+
+#[cfg(feature = "app")]
+fn result_from_raw(result: AppWorkerResult) -> Result<(), AppWorkerError> {
+	match result {
+		AppWorkerResult::Success => Ok(()),
+		AppWorkerResult::NoWorker => Err(AppWorkerError::NoWorker),
+		AppWorkerResult::DifferentApp => Err(AppWorkerError::DifferentApp),
+		AppWorkerResult::NotRunning => Err(AppWorkerError::NotRunning),
+		AppWorkerResult::AlreadyRunning => Err(AppWorkerError::AlreadyRunning),
+		AppWorkerResult::AskingConfirmation => Err(AppWorkerError::AskingConfirmation),
+	}
+}
+
+#[cfg(feature = "app")]
+#[must_use]
+pub fn is_running() -> bool {
+	unsafe { app_worker_is_running() }
+}
+
+/// # Errors
+///
+/// See [`AppWorkerError`].
+#[cfg(feature = "app")]
+pub fn launch() -> Result<(), AppWorkerError> {
+	result_from_raw(unsafe { app_worker_launch() })
+}
+
+/// # Errors
+///
+/// See [`AppWorkerError`].
+#[cfg(feature = "app")]
+pub fn kill() -> Result<(), AppWorkerError> {
+	result_from_raw(unsafe { app_worker_kill() })
+}
+
+/// Sends `message` to the other side (app or worker), tagged with `type`.
+pub fn send_message(r#type: u8, message: AppWorkerMessage) {
+	unsafe { app_worker_send_message(r#type, &message) }
+}
+
+static HANDLER: HandlerSlot<dyn FnMut(u16, &AppWorkerMessage)> = HandlerSlot::new();
+
+/// Active worker message subscription. Unsubscribes when dropped.
+#[must_use = "The subscription ends when this guard is dropped."]
+pub struct AppWorkerMessageSubscription(PhantomData<*mut ()>);
+
+/// Subscribes `handler` to messages from the other side (app or worker).
+///
+/// # Errors
+///
+/// Iff there's already a message subscription, the handler can't be moved onto the heap or the SDK refuses the subscription.
+pub fn subscribe<F: 'static + FnMut(u16, &AppWorkerMessage)>(
+	handler: F,
+) -> Result<AppWorkerMessageSubscription, SubscriptionError<F>> {
+	extern "C" fn raw_message(r#type: u16, message: &sysAppWorkerMessage) {
+		HANDLER.call(|handler| handler(r#type, message))
+	}
+
+	HANDLER.fill(handler)?;
+	match unsafe { app_worker_message_subscribe(raw_message) } {
+		true => Ok(AppWorkerMessageSubscription(PhantomData)),
+		false => Err(SubscriptionError::Unavailable(Box::into_inner(unsafe {
+			Box::downcast_unchecked(HANDLER.take().unsafe_unwrap())
+		}))),
+	}
+}
+
+impl Drop for AppWorkerMessageSubscription {
+	fn drop(&mut self) {
+		unsafe { app_worker_message_unsubscribe() };
+		drop(HANDLER.take())
+	}
+}
@@ -0,0 +1,255 @@
+//! Bitmaps, loaded from resources or created at runtime.
+
+use super::graphics_types::{Color8, GPoint, GRect, GSize};
+use crate::{Box, Handle};
+use core::{ptr::NonNull, slice};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::graphics_types::{
+	GBitmap as sysGBitmap, GBitmapFormat as sysGBitmapFormat, *,
+};
+
+pub type GBitmapFormat = sysGBitmapFormat;
+
+/// An owned bitmap. Destroyed when dropped.
+///
+/// Layers and menus that display a bitmap borrow it, so it can't be dropped while still in use.
+pub struct Bitmap(
+	pub(crate) Handle<'static, sysGBitmap>,
+	/// Pixel data supplied by the app, if any. Dropped after the bitmap is destroyed.
+	Option<Box<'static, [u8]>>,
+	/// Palette supplied by the app, if any. Dropped after the bitmap is destroyed.
+	Option<Box<'static, [Color8]>>,
+);
+
+/// The pixels of one row of a [`Bitmap`] that are actually stored.
+///
+/// On round displays, rows of circular bitmaps only contain the visible pixels from `min_x` to `max_x`.
+/// `data` starts with the byte containing pixel `min_x` and ends with the byte containing pixel `max_x`.
+///
+/// [`Bitmap`]: ./struct.Bitmap.html
+pub struct BitmapDataRow<'a> {
+	pub data: &'a [u8],
+	pub min_x: i16,
+	pub max_x: i16,
+}
+
+/// Like [`BitmapDataRow`], but allows changing the pixels.
+///
+/// [`BitmapDataRow`]: ./struct.BitmapDataRow.html
+pub struct BitmapDataRowMut<'a> {
+	pub data: &'a mut [u8],
+	pub min_x: i16,
+	pub max_x: i16,
+}
+
+/// The number of bits each pixel takes up in `format`.
+#[must_use]
+pub fn bits_per_pixel(format: GBitmapFormat) -> u8 {
+	match format {
+		GBitmapFormat::Format1Bit | GBitmapFormat::Format1BitPalette => 1,
+		GBitmapFormat::Format2BitPalette => 2,
+		GBitmapFormat::Format4BitPalette => 4,
+		GBitmapFormat::Format8Bit | GBitmapFormat::Format8BitCircular => 8,
+	}
+}
+
+/// The number of colors in the palette of `format`, or [`None`] if it doesn't use one.
+#[must_use]
+pub fn palette_size(format: GBitmapFormat) -> Option<usize> {
+	match format {
+		GBitmapFormat::Format1BitPalette => Some(2),
+		GBitmapFormat::Format2BitPalette => Some(4),
+		GBitmapFormat::Format4BitPalette => Some(16),
+		GBitmapFormat::Format1Bit
+		| GBitmapFormat::Format8Bit
+		| GBitmapFormat::Format8BitCircular => None,
+	}
+}
+
+impl Bitmap {
+	fn from_raw(raw_bitmap: Option<&'static mut sysGBitmap>) -> Result<Self, ()> {
+		match raw_bitmap {
+			Some(raw_bitmap) => Ok(Self(Handle::new(raw_bitmap), None, None)),
+			None => Err(()),
+		}
+	}
+
+	/// Loads the bitmap resource with the given ID.
+	///
+	/// # Errors
+	///
+	/// Iff the resource couldn't be loaded, for example because there's not enough heap memory.
+	pub fn with_resource(resource_id: u32) -> Result<Self, ()> {
+		Self::from_raw(unsafe { gbitmap_create_with_resource(resource_id) })
+	}
+
+	/// Decodes a PNG image. `png_data` is only read during this call.
+	///
+	/// # Errors
+	///
+	/// Iff `png_data` isn't a supported PNG image or there's not enough heap memory.
+	pub fn from_png_data(png_data: &[u8]) -> Result<Self, ()> {
+		Self::from_raw(unsafe { gbitmap_create_from_png_data(png_data.as_ptr(), png_data.len()) })
+	}
+
+	/// Creates a bitmap of `size` with uninitialised (but allocated) pixel data.
+	///
+	/// Palettized formats start out with a palette of the right size, see [`.set_palette(…)`](#method.set_palette).
+	///
+	/// # Errors
+	///
+	/// Iff there's not enough heap memory.
+	pub fn blank(size: GSize, format: GBitmapFormat) -> Result<Self, ()> {
+		Self::from_raw(unsafe { gbitmap_create_blank(size, format) })
+	}
+
+	/// Creates a bitmap of `size` that uses `data` as pixel data, with rows `row_size_bytes` apart.
+	///
+	/// # Errors
+	///
+	/// Iff `data` is too short for `size` and `row_size_bytes`, rows are too short for `format`, or there's not enough heap memory.
+	/// `data` is returned in either case.
+	#[allow(clippy::cast_sign_loss)] // Checked.
+	pub fn with_data(
+		size: GSize,
+		format: GBitmapFormat,
+		row_size_bytes: u16,
+		mut data: Box<'static, [u8]>,
+	) -> Result<Self, Box<'static, [u8]>> {
+		if size.w < 0
+			|| size.h < 0
+			|| usize::from(row_size_bytes) * 8
+				< size.w as usize * usize::from(bits_per_pixel(format))
+			|| data.len() < usize::from(row_size_bytes) * size.h as usize
+		{
+			return Err(data);
+		}
+		let mut bitmap =
+			match Self::from_raw(unsafe { gbitmap_create_blank(GSize { w: 0, h: 0 }, format) }) {
+				Ok(bitmap) => bitmap,
+				Err(()) => return Err(data),
+			};
+		unsafe {
+			//SAFETY: `data` is only dropped after the bitmap is destroyed.
+			gbitmap_set_data(
+				bitmap.0.as_mut_unchecked(),
+				data.as_mut_ptr(),
+				format,
+				row_size_bytes,
+				false,
+			);
+			gbitmap_set_bounds(
+				bitmap.0.as_mut_unchecked(),
+				GRect {
+					origin: GPoint { x: 0, y: 0 },
+					size,
+				},
+			)
+		}
+		bitmap.1 = Some(data);
+		Ok(bitmap)
+	}
+
+	/// The part of the pixel data that's drawn, relative to the top left of the data.
+	#[must_use]
+	pub fn get_bounds(&self) -> GRect {
+		unsafe { gbitmap_get_bounds(&*self.0) }
+	}
+
+	pub fn set_bounds(&mut self, bounds: GRect) {
+		unsafe { gbitmap_set_bounds(self.0.as_mut_unchecked(), bounds) }
+	}
+
+	#[must_use]
+	pub fn get_format(&self) -> GBitmapFormat {
+		unsafe { gbitmap_get_format(&*self.0) }
+	}
+
+	/// The distance between rows of pixel data, in bytes.
+	#[must_use]
+	pub fn get_bytes_per_row(&self) -> u16 {
+		unsafe { gbitmap_get_bytes_per_row(&*self.0) }
+	}
+
+	/// The palette of a palettized bitmap, or [`None`] for other formats.
+	#[must_use]
+	pub fn get_palette(&self) -> Option<&[Color8]> {
+		let palette_size = palette_size(self.get_format())?;
+		let palette = unsafe { gbitmap_get_palette(&*self.0) };
+		if palette.is_null() {
+			None
+		} else {
+			Some(unsafe { slice::from_raw_parts(palette, palette_size) })
+		}
+	}
+
+	/// Replaces the palette of a palettized bitmap.
+	///
+	/// # Errors
+	///
+	/// Iff this bitmap's format doesn't use a palette or `palette` has the wrong number of colors, in which case `palette` is returned.
+	pub fn set_palette(
+		&mut self,
+		mut palette: Box<'static, [Color8]>,
+	) -> Result<(), Box<'static, [Color8]>> {
+		if palette_size(self.get_format()) != Some(palette.len()) {
+			return Err(palette);
+		}
+		unsafe {
+			//SAFETY: The previous palette is only dropped after the bitmap stopped referring to it.
+			gbitmap_set_palette(self.0.as_mut_unchecked(), palette.as_mut_ptr(), false)
+		}
+		self.2 = Some(palette);
+		Ok(())
+	}
+
+	/// The raw parts of row `y`, or [`None`] if `y` is outside the bounds.
+	#[allow(clippy::cast_sign_loss)] // Checked.
+	fn raw_data_row(&self, y: i16) -> Option<(*mut u8, usize, i16, i16)> {
+		let bounds = self.get_bounds();
+		if y < 0.max(bounds.origin.y) || y >= bounds.origin.y + bounds.size.h {
+			return None;
+		}
+		let info = unsafe { gbitmap_get_data_row_info(&*self.0, y as u16) };
+		if info.min_x < 0 || info.max_x < info.min_x {
+			return Some((NonNull::dangling().as_ptr(), 0, info.min_x, info.max_x));
+		}
+		let bits_per_pixel = usize::from(bits_per_pixel(self.get_format()));
+		let first_byte = info.min_x as usize * bits_per_pixel / 8;
+		let last_byte = info.max_x as usize * bits_per_pixel / 8;
+		Some((
+			unsafe { info.data.add(first_byte) },
+			last_byte - first_byte + 1,
+			info.min_x,
+			info.max_x,
+		))
+	}
+
+	/// The stored pixels of row `y`, or [`None`] if `y` is outside the bounds.
+	#[must_use]
+	pub fn get_data_row_info(&self, y: i16) -> Option<BitmapDataRow<'_>> {
+		let (data, len, min_x, max_x) = self.raw_data_row(y)?;
+		Some(BitmapDataRow {
+			data: unsafe { slice::from_raw_parts(data, len) },
+			min_x,
+			max_x,
+		})
+	}
+
+	/// Like [`.get_data_row_info(…)`](#method.get_data_row_info), but allows changing the pixels.
+	pub fn get_data_row_info_mut(&mut self, y: i16) -> Option<BitmapDataRowMut<'_>> {
+		let (data, len, min_x, max_x) = self.raw_data_row(y)?;
+		Some(BitmapDataRowMut {
+			data: unsafe { slice::from_raw_parts_mut(data, len) },
+			min_x,
+			max_x,
+		})
+	}
+}
+
+impl Drop for Bitmap {
+	fn drop(&mut self) {
+		unsafe { gbitmap_destroy(self.0.duplicate().unwrap()) }
+		// The app-supplied data and palette are dropped after this, as fields.
+	}
+}
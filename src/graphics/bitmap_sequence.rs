@@ -0,0 +1,126 @@
+//! Animated images (APNG), decoded frame by frame into a [`Bitmap`].
+//!
+//! [`Bitmap`]: ../struct.Bitmap.html
+
+use super::{
+	bitmap::{Bitmap, GBitmapFormat},
+	graphics_types::GSize,
+};
+use crate::Handle;
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::graphics_types::{GBitmapSequence as sysGBitmapSequence, *};
+
+/// A decoder for an animated image. Destroyed when dropped.
+pub struct BitmapSequence(Handle<'static, sysGBitmapSequence>);
+
+impl BitmapSequence {
+	/// Loads the APNG resource with the given ID.
+	///
+	/// # Errors
+	///
+	/// Iff the resource couldn't be loaded, for example because there's not enough heap memory.
+	pub fn with_resource(resource_id: u32) -> Result<Self, ()> {
+		match unsafe { gbitmap_sequence_create_with_resource(resource_id) } {
+			Some(raw_sequence) => Ok(Self(Handle::new(raw_sequence))),
+			None => Err(()),
+		}
+	}
+
+	/// Creates a blank bitmap that frames of this sequence can be decoded into.
+	///
+	/// # Errors
+	///
+	/// Iff there's not enough heap memory.
+	pub fn create_frame_bitmap(&self) -> Result<Bitmap, ()> {
+		Bitmap::blank(self.get_bitmap_size(), GBitmapFormat::Format8Bit)
+	}
+
+	/// Decodes the next frame into `bitmap`.
+	///
+	/// Returns how long the frame should be shown in milliseconds, or [`None`] once the sequence has finished playing.
+	pub fn update_bitmap_next_frame(&self, bitmap: &mut Bitmap) -> Option<u32> {
+		let mut delay_ms = 0;
+		match unsafe {
+			gbitmap_sequence_update_bitmap_next_frame(
+				self.0.as_mut_unchecked(),
+				bitmap.0.as_mut_unchecked(),
+				&mut delay_ms,
+			)
+		} {
+			true => Some(delay_ms),
+			false => None,
+		}
+	}
+
+	/// Decodes the frame shown `elapsed_ms` milliseconds into the sequence into `bitmap`.
+	///
+	/// # Errors
+	///
+	/// Iff the sequence has finished playing by then.
+	pub fn update_bitmap_by_elapsed(&self, bitmap: &mut Bitmap, elapsed_ms: u32) -> Result<(), ()> {
+		match unsafe {
+			gbitmap_sequence_update_bitmap_by_elapsed(
+				self.0.as_mut_unchecked(),
+				bitmap.0.as_mut_unchecked(),
+				elapsed_ms,
+			)
+		} {
+			true => Ok(()),
+			false => Err(()),
+		}
+	}
+
+	/// Rewinds to the first frame.
+	///
+	/// # Errors
+	///
+	/// Iff the sequence couldn't be restarted.
+	pub fn restart(&self) -> Result<(), ()> {
+		match unsafe { gbitmap_sequence_restart(self.0.as_mut_unchecked()) } {
+			true => Ok(()),
+			false => Err(()),
+		}
+	}
+
+	/// How often the sequence plays. [`u32::MAX`] means forever.
+	#[must_use]
+	pub fn get_play_count(&self) -> u32 {
+		unsafe { gbitmap_sequence_get_play_count(&*self.0) }
+	}
+
+	pub fn set_play_count(&self, play_count: u32) {
+		unsafe { gbitmap_sequence_set_play_count(self.0.as_mut_unchecked(), play_count) };
+	}
+
+	/// The duration of all repetitions, in milliseconds.
+	#[must_use]
+	pub fn get_total_duration(&self) -> u32 {
+		unsafe { gbitmap_sequence_get_total_duration(&*self.0) }
+	}
+
+	#[must_use]
+	pub fn get_total_num_frames(&self) -> u32 {
+		unsafe { gbitmap_sequence_get_total_num_frames(&*self.0) }
+	}
+
+	/// The index of the frame that was decoded last, or [`None`] before the first one.
+	#[must_use]
+	#[allow(clippy::cast_sign_loss)] // Checked.
+	pub fn get_current_frame_idx(&self) -> Option<u32> {
+		match unsafe { gbitmap_sequence_get_current_frame_idx(&*self.0) } {
+			index if index >= 0 => Some(index as u32),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	pub fn get_bitmap_size(&self) -> GSize {
+		unsafe { gbitmap_sequence_get_bitmap_size(&*self.0) }
+	}
+}
+
+impl Drop for BitmapSequence {
+	fn drop(&mut self) {
+		unsafe { gbitmap_sequence_destroy(self.0.duplicate().unwrap()) }
+	}
+}
@@ -0,0 +1,295 @@
+//! Pebble Draw Command (PDC) vector images and animated sequences.
+//!
+//! The individual commands can be changed at runtime, for example to recolor an icon.
+
+use super::{
+	graphics_context::GContext,
+	graphics_types::{Color8, GPoint, GSize},
+};
+use crate::Handle;
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::draw_commands::{
+	GDrawCommand as sysGDrawCommand, GDrawCommandFrame as sysGDrawCommandFrame,
+	GDrawCommandImage as sysGDrawCommandImage, GDrawCommandList as sysGDrawCommandList,
+	GDrawCommandSequence as sysGDrawCommandSequence, GDrawCommandType as sysGDrawCommandType, *,
+};
+
+pub type GDrawCommandType = sysGDrawCommandType;
+
+/// A vector image. Destroyed when dropped.
+pub struct DrawCommandImage(Handle<'static, sysGDrawCommandImage>);
+
+/// A vector animation, made up of [frames]. Destroyed when dropped.
+///
+/// [frames]: ./struct.DrawCommandFrame.html
+pub struct DrawCommandSequence(Handle<'static, sysGDrawCommandSequence>);
+
+/// One frame of a [`DrawCommandSequence`].
+///
+/// [`DrawCommandSequence`]: ./struct.DrawCommandSequence.html
+pub struct DrawCommandFrame<'a> {
+	frame: Handle<'a, sysGDrawCommandFrame>,
+	sequence: &'a DrawCommandSequence,
+}
+
+/// The commands an image or frame is drawn with.
+pub struct DrawCommandList<'a>(Handle<'a, sysGDrawCommandList>);
+
+/// A single path or circle.
+pub struct DrawCommand<'a>(Handle<'a, sysGDrawCommand>);
+
+impl DrawCommandImage {
+	/// Loads the PDC image resource with the given ID.
+	///
+	/// # Errors
+	///
+	/// Iff the resource couldn't be loaded, for example because there's not enough heap memory.
+	pub fn with_resource(resource_id: u32) -> Result<Self, ()> {
+		match unsafe { gdraw_command_image_create_with_resource(resource_id) } {
+			Some(raw_image) => Ok(Self(Handle::new(raw_image))),
+			None => Err(()),
+		}
+	}
+
+	/// Draws this image with its top left corner at `offset`.
+	pub fn draw(&self, ctx: &mut GContext, offset: GPoint) {
+		unsafe { gdraw_command_image_draw(&mut *ctx.0, self.0.as_mut_unchecked(), offset) }
+	}
+
+	#[must_use]
+	pub fn get_bounds_size(&self) -> GSize {
+		unsafe { gdraw_command_image_get_bounds_size(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_bounds_size(&self, size: GSize) {
+		unsafe { gdraw_command_image_set_bounds_size(self.0.as_mut_unchecked(), size) }
+	}
+
+	#[must_use]
+	pub fn command_list(&self) -> DrawCommandList<'_> {
+		DrawCommandList(Handle::new(unsafe {
+			gdraw_command_image_get_command_list(self.0.as_mut_unchecked())
+		}))
+	}
+}
+
+impl Drop for DrawCommandImage {
+	fn drop(&mut self) {
+		unsafe { gdraw_command_image_destroy(self.0.duplicate().unwrap()) }
+	}
+}
+
+impl DrawCommandSequence {
+	/// Loads the PDC sequence resource with the given ID.
+	///
+	/// # Errors
+	///
+	/// Iff the resource couldn't be loaded, for example because there's not enough heap memory.
+	pub fn with_resource(resource_id: u32) -> Result<Self, ()> {
+		match unsafe { gdraw_command_sequence_create_with_resource(resource_id) } {
+			Some(raw_sequence) => Ok(Self(Handle::new(raw_sequence))),
+			None => Err(()),
+		}
+	}
+
+	/// The frame at `index`, or [`None`] if out of range.
+	#[must_use]
+	pub fn get_frame_by_index(&self, index: u32) -> Option<DrawCommandFrame<'_>> {
+		unsafe { gdraw_command_sequence_get_frame_by_index(self.0.as_mut_unchecked(), index) }.map(
+			|frame| DrawCommandFrame {
+				frame: Handle::new(frame),
+				sequence: self,
+			},
+		)
+	}
+
+	/// The frame shown `elapsed_ms` milliseconds into the sequence, taking the play count into account.
+	#[must_use]
+	pub fn get_frame_by_elapsed(&self, elapsed_ms: u32) -> Option<DrawCommandFrame<'_>> {
+		unsafe {
+			gdraw_command_sequence_get_frame_by_elapsed(self.0.as_mut_unchecked(), elapsed_ms)
+		}
+		.map(|frame| DrawCommandFrame {
+			frame: Handle::new(frame),
+			sequence: self,
+		})
+	}
+
+	#[must_use]
+	pub fn get_num_frames(&self) -> u32 {
+		unsafe { gdraw_command_sequence_get_num_frames(self.0.as_mut_unchecked()) }
+	}
+
+	/// The duration of all repetitions, in milliseconds.
+	#[must_use]
+	pub fn get_total_duration(&self) -> u32 {
+		unsafe { gdraw_command_sequence_get_total_duration(self.0.as_mut_unchecked()) }
+	}
+
+	#[must_use]
+	pub fn get_play_count(&self) -> u32 {
+		unsafe { gdraw_command_sequence_get_play_count(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_play_count(&self, play_count: u32) {
+		unsafe { gdraw_command_sequence_set_play_count(self.0.as_mut_unchecked(), play_count) }
+	}
+
+	#[must_use]
+	pub fn get_bounds_size(&self) -> GSize {
+		unsafe { gdraw_command_sequence_get_bounds_size(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_bounds_size(&self, size: GSize) {
+		unsafe { gdraw_command_sequence_set_bounds_size(self.0.as_mut_unchecked(), size) }
+	}
+}
+
+impl Drop for DrawCommandSequence {
+	fn drop(&mut self) {
+		unsafe { gdraw_command_sequence_destroy(self.0.duplicate().unwrap()) }
+	}
+}
+
+impl<'a> DrawCommandFrame<'a> {
+	/// Draws this frame with its top left corner at `offset`.
+	pub fn draw(&self, ctx: &mut GContext, offset: GPoint) {
+		unsafe {
+			gdraw_command_frame_draw(
+				&mut *ctx.0,
+				self.sequence.0.as_mut_unchecked(),
+				self.frame.as_mut_unchecked(),
+				offset,
+			)
+		}
+	}
+
+	/// How long this frame is shown, in milliseconds.
+	#[must_use]
+	pub fn get_duration(&self) -> u32 {
+		unsafe { gdraw_command_frame_get_duration(self.frame.as_mut_unchecked()) }
+	}
+
+	pub fn set_duration(&self, duration_ms: u32) {
+		unsafe { gdraw_command_frame_set_duration(self.frame.as_mut_unchecked(), duration_ms) }
+	}
+
+	#[must_use]
+	pub fn command_list(&self) -> DrawCommandList<'_> {
+		DrawCommandList(Handle::new(unsafe {
+			gdraw_command_frame_get_command_list(self.frame.as_mut_unchecked())
+		}))
+	}
+}
+
+impl<'a> DrawCommandList<'a> {
+	#[must_use]
+	pub fn get_num_commands(&self) -> u32 {
+		unsafe { gdraw_command_list_get_num_commands(self.0.as_mut_unchecked()) }
+	}
+
+	/// The command at `index`, or [`None`] if out of range.
+	#[must_use]
+	pub fn get_command(&self, index: u16) -> Option<DrawCommand<'_>> {
+		if u32::from(index) >= self.get_num_commands() {
+			return None;
+		}
+		unsafe { gdraw_command_list_get_command(self.0.as_mut_unchecked(), index) }
+			.map(|command| DrawCommand(Handle::new(command)))
+	}
+
+	/// Draws all commands, without any offset.
+	pub fn draw(&self, ctx: &mut GContext) {
+		unsafe { gdraw_command_list_draw(&mut *ctx.0, self.0.as_mut_unchecked()) }
+	}
+}
+
+impl<'a> DrawCommand<'a> {
+	#[must_use]
+	pub fn get_type(&self) -> GDrawCommandType {
+		unsafe { gdraw_command_get_type(self.0.as_mut_unchecked()) }
+	}
+
+	#[must_use]
+	pub fn get_fill_color(&self) -> Color8 {
+		unsafe { gdraw_command_get_fill_color(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_fill_color(&self, fill_color: Color8) {
+		unsafe { gdraw_command_set_fill_color(self.0.as_mut_unchecked(), fill_color) }
+	}
+
+	#[must_use]
+	pub fn get_stroke_color(&self) -> Color8 {
+		unsafe { gdraw_command_get_stroke_color(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_stroke_color(&self, stroke_color: Color8) {
+		unsafe { gdraw_command_set_stroke_color(self.0.as_mut_unchecked(), stroke_color) }
+	}
+
+	#[must_use]
+	pub fn get_stroke_width(&self) -> u8 {
+		unsafe { gdraw_command_get_stroke_width(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_stroke_width(&self, stroke_width: u8) {
+		unsafe { gdraw_command_set_stroke_width(self.0.as_mut_unchecked(), stroke_width) }
+	}
+
+	#[must_use]
+	pub fn get_num_points(&self) -> u16 {
+		unsafe { gdraw_command_get_num_points(self.0.as_mut_unchecked()) }
+	}
+
+	/// The point at `index`, or [`None`] if out of range.
+	#[must_use]
+	pub fn get_point(&self, index: u16) -> Option<GPoint> {
+		if index < self.get_num_points() {
+			Some(unsafe { gdraw_command_get_point(self.0.as_mut_unchecked(), index) })
+		} else {
+			None
+		}
+	}
+
+	/// # Errors
+	///
+	/// Iff `index` is out of range.
+	pub fn set_point(&self, index: u16, point: GPoint) -> Result<(), ()> {
+		if index < self.get_num_points() {
+			unsafe { gdraw_command_set_point(self.0.as_mut_unchecked(), index, point) };
+			Ok(())
+		} else {
+			Err(())
+		}
+	}
+
+	/// The radius of a circle command.
+	#[must_use]
+	pub fn get_radius(&self) -> u16 {
+		unsafe { gdraw_command_get_radius(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_radius(&self, radius: u16) {
+		unsafe { gdraw_command_set_radius(self.0.as_mut_unchecked(), radius) }
+	}
+
+	/// Whether a path command is left open, rather than connecting its last point to the first.
+	#[must_use]
+	pub fn get_path_open(&self) -> bool {
+		unsafe { gdraw_command_get_path_open(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_path_open(&self, path_open: bool) {
+		unsafe { gdraw_command_set_path_open(self.0.as_mut_unchecked(), path_open) }
+	}
+
+	#[must_use]
+	pub fn get_hidden(&self) -> bool {
+		unsafe { gdraw_command_get_hidden(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_hidden(&self, hidden: bool) {
+		unsafe { gdraw_command_set_hidden(self.0.as_mut_unchecked(), hidden) }
+	}
+}
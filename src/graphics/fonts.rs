@@ -0,0 +1,113 @@
+//! System and custom fonts.
+
+use crate::{
+	foundation::resources,
+	standard_c::{CStr, Static},
+	Handle,
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::fonts::{FontInfo as sysFontInfo, *};
+
+/// The fonts built into the firmware, named like the SDK's `FONT_KEY_*` constants.
+///
+/// Not every font contains every character. The `Subset` and `Numbers` fonts in particular are limited to digits and a few symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemFont {
+	Gothic09,
+	Gothic14,
+	Gothic14Bold,
+	Gothic18,
+	Gothic18Bold,
+	Gothic24,
+	Gothic24Bold,
+	Gothic28,
+	Gothic28Bold,
+	Bitham18LightSubset,
+	Bitham30Black,
+	Bitham34LightSubset,
+	Bitham34MediumNumbers,
+	Bitham42Bold,
+	Bitham42Light,
+	Bitham42MediumNumbers,
+	RobotoCondensed21,
+	RobotoBoldSubset49,
+	DroidSerif28Bold,
+	Leco20BoldNumbers,
+	Leco26BoldNumbersAmPm,
+	Leco28LightNumbers,
+	Leco32BoldNumbers,
+	Leco36BoldNumbers,
+	Leco38BoldNumbers,
+	Leco42Numbers,
+}
+
+impl SystemFont {
+	/// The font key to look this font up with.
+	#[must_use]
+	pub fn key(self) -> &'static CStr<Static> {
+		let key = match self {
+			SystemFont::Gothic09 => "RESOURCE_ID_GOTHIC_09\0",
+			SystemFont::Gothic14 => "RESOURCE_ID_GOTHIC_14\0",
+			SystemFont::Gothic14Bold => "RESOURCE_ID_GOTHIC_14_BOLD\0",
+			SystemFont::Gothic18 => "RESOURCE_ID_GOTHIC_18\0",
+			SystemFont::Gothic18Bold => "RESOURCE_ID_GOTHIC_18_BOLD\0",
+			SystemFont::Gothic24 => "RESOURCE_ID_GOTHIC_24\0",
+			SystemFont::Gothic24Bold => "RESOURCE_ID_GOTHIC_24_BOLD\0",
+			SystemFont::Gothic28 => "RESOURCE_ID_GOTHIC_28\0",
+			SystemFont::Gothic28Bold => "RESOURCE_ID_GOTHIC_28_BOLD\0",
+			SystemFont::Bitham18LightSubset => "RESOURCE_ID_BITHAM_18_LIGHT_SUBSET\0",
+			SystemFont::Bitham30Black => "RESOURCE_ID_BITHAM_30_BLACK\0",
+			SystemFont::Bitham34LightSubset => "RESOURCE_ID_BITHAM_34_LIGHT_SUBSET\0",
+			SystemFont::Bitham34MediumNumbers => "RESOURCE_ID_BITHAM_34_MEDIUM_NUMBERS\0",
+			SystemFont::Bitham42Bold => "RESOURCE_ID_BITHAM_42_BOLD\0",
+			SystemFont::Bitham42Light => "RESOURCE_ID_BITHAM_42_LIGHT\0",
+			SystemFont::Bitham42MediumNumbers => "RESOURCE_ID_BITHAM_42_MEDIUM_NUMBERS\0",
+			SystemFont::RobotoCondensed21 => "RESOURCE_ID_ROBOTO_CONDENSED_21\0",
+			SystemFont::RobotoBoldSubset49 => "RESOURCE_ID_ROBOTO_BOLD_SUBSET_49\0",
+			SystemFont::DroidSerif28Bold => "RESOURCE_ID_DROID_SERIF_28_BOLD\0",
+			SystemFont::Leco20BoldNumbers => "RESOURCE_ID_LECO_20_BOLD_NUMBERS\0",
+			SystemFont::Leco26BoldNumbersAmPm => "RESOURCE_ID_LECO_26_BOLD_NUMBERS_AM_PM\0",
+			SystemFont::Leco28LightNumbers => "RESOURCE_ID_LECO_28_LIGHT_NUMBERS\0",
+			SystemFont::Leco32BoldNumbers => "RESOURCE_ID_LECO_32_BOLD_NUMBERS\0",
+			SystemFont::Leco36BoldNumbers => "RESOURCE_ID_LECO_36_BOLD_NUMBERS\0",
+			SystemFont::Leco38BoldNumbers => "RESOURCE_ID_LECO_38_BOLD_NUMBERS\0",
+			SystemFont::Leco42Numbers => "RESOURCE_ID_LECO_42_NUMBERS\0",
+		};
+		unsafe { CStr::from_static_zero_terminated_unchecked(key) }
+	}
+}
+
+/// A font to draw text with. Custom fonts are unloaded when dropped.
+///
+/// Text layers borrow their font, so it can't be unloaded while still in use.
+pub struct Font(pub(crate) Handle<'static, sysFontInfo>, bool);
+
+impl Font {
+	#[must_use]
+	pub fn system(system_font: SystemFont) -> Self {
+		Self(
+			Handle::new(unsafe { fonts_get_system_font(system_font.key().as_c_str()) }),
+			false,
+		)
+	}
+
+	/// Loads the font resource with the given ID.
+	///
+	/// # Errors
+	///
+	/// Iff the font couldn't be loaded, for example because there's not enough heap memory.
+	pub fn load_custom(resource_id: u32) -> Result<Self, ()> {
+		match unsafe { fonts_load_custom_font(resources::get_handle(resource_id)) } {
+			Some(raw_font) => Ok(Self(Handle::new(raw_font), true)),
+			None => Err(()),
+		}
+	}
+}
+
+impl Drop for Font {
+	fn drop(&mut self) {
+		if self.1 {
+			unsafe { fonts_unload_custom_font(self.0.duplicate().unwrap()) }
+		}
+	}
+}
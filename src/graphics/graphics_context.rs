@@ -0,0 +1,95 @@
+//! Drawing state and primitives, as passed to layer update procedures and drawing callbacks.
+
+use super::{
+	fonts::Font,
+	graphics_types::{Color8, GCornerMask, GPoint, GRect},
+	text::{GTextAlignment, GTextOverflowMode},
+};
+use crate::{
+	standard_c::{CStr, Storage},
+	Handle,
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::{
+	drawing_primitives::*, graphics_context::*, graphics_types::GContext as sysGContext,
+	text::graphics_draw_text,
+};
+
+/// A graphics context, only valid for the duration of the drawing callback it was passed to.
+pub struct GContext<'a>(pub(crate) Handle<'a, sysGContext>);
+
+impl<'a> GContext<'a> {
+	pub(crate) fn from_raw(raw_context: &'a mut sysGContext) -> Self {
+		Self(Handle::new(raw_context))
+	}
+
+	pub fn set_fill_color(&mut self, color: Color8) {
+		unsafe { graphics_context_set_fill_color(&mut *self.0, color) }
+	}
+
+	pub fn set_stroke_color(&mut self, color: Color8) {
+		unsafe { graphics_context_set_stroke_color(&mut *self.0, color) }
+	}
+
+	pub fn set_text_color(&mut self, color: Color8) {
+		unsafe { graphics_context_set_text_color(&mut *self.0, color) }
+	}
+
+	pub fn set_stroke_width(&mut self, stroke_width: u8) {
+		unsafe { graphics_context_set_stroke_width(&mut *self.0, stroke_width) }
+	}
+
+	pub fn set_antialiased(&mut self, enable: bool) {
+		unsafe { graphics_context_set_antialiased(&mut *self.0, enable) }
+	}
+
+	pub fn draw_pixel(&mut self, point: GPoint) {
+		unsafe { graphics_draw_pixel(&mut *self.0, point) }
+	}
+
+	pub fn draw_line(&mut self, p0: GPoint, p1: GPoint) {
+		unsafe { graphics_draw_line(&mut *self.0, p0, p1) }
+	}
+
+	pub fn draw_rect(&mut self, rect: GRect) {
+		unsafe { graphics_draw_rect(&mut *self.0, rect) }
+	}
+
+	pub fn fill_rect(&mut self, rect: GRect, corner_radius: u16, corner_mask: GCornerMask) {
+		unsafe { graphics_fill_rect(&mut *self.0, rect, corner_radius, corner_mask) }
+	}
+
+	pub fn draw_circle(&mut self, center: GPoint, radius: u16) {
+		unsafe { graphics_draw_circle(&mut *self.0, center, radius) }
+	}
+
+	pub fn fill_circle(&mut self, center: GPoint, radius: u16) {
+		unsafe { graphics_fill_circle(&mut *self.0, center, radius) }
+	}
+
+	/// Draws `text` into `bounds`, in the current text color.
+	///
+	/// Use [`text::size`] to measure the text beforehand.
+	///
+	/// [`text::size`]: ../text/fn.size.html
+	pub fn draw_text(
+		&mut self,
+		text: &CStr<impl Storage>,
+		font: &Font,
+		bounds: GRect,
+		overflow_mode: GTextOverflowMode,
+		alignment: GTextAlignment,
+	) {
+		unsafe {
+			graphics_draw_text(
+				&mut *self.0,
+				text.as_c_str(),
+				&*font.0,
+				bounds,
+				overflow_mode,
+				alignment,
+				None,
+			)
+		}
+	}
+}
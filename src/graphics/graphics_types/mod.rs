@@ -3,4 +3,8 @@ use pebble_sys::graphics::graphics_types::*;
 
 pub mod color_definitions;
 
+pub use pebble_sys::graphics::graphics_types::{
+	GAlign, GCompOp, GCornerMask, GPoint, GRect, GSize,
+};
+
 pub type Color8 = GColor8;
@@ -1 +1,11 @@
+pub mod bitmap;
+pub mod bitmap_sequence;
+pub mod draw_command;
+pub mod fonts;
+pub mod graphics_context;
 pub mod graphics_types;
+pub mod path;
+pub mod text;
+
+pub use bitmap::Bitmap;
+pub use bitmap_sequence::BitmapSequence;
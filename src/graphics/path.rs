@@ -0,0 +1,97 @@
+//! Polygons and polylines that can be moved and rotated, for example analog watch hands.
+
+use super::{graphics_context::GContext, graphics_types::GPoint};
+use crate::{foundation::math::Angle, Box, Handle};
+use core::convert::TryInto;
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::path::{GPath as sysGPath, GPathInfo as sysGPathInfo, *};
+
+/// The points of a [`Path`], which the SDK keeps referring to.
+///
+/// [`Path`]: ./struct.Path.html
+enum PathPoints {
+	Owned(Box<'static, [GPoint]>),
+	Static(&'static [GPoint]),
+}
+
+/// A path through a list of points, drawn relative to its offset and rotated around the origin.
+pub struct Path(
+	Handle<'static, sysGPath>,
+	/// Dropped after the path is destroyed.
+	PathPoints,
+);
+
+impl Path {
+	fn new(mut points: PathPoints) -> Result<Self, PathPoints> {
+		let (points_pointer, num_points) = match &mut points {
+			PathPoints::Owned(points) => (points.as_mut_ptr(), points.len()),
+			// The SDK only reads the points. Offset and rotation are stored in the path itself.
+			PathPoints::Static(points) => (points.as_ptr() as *mut GPoint, points.len()),
+		};
+		let num_points = match num_points.try_into() {
+			Ok(num_points) => num_points,
+			Err(_) => return Err(points),
+		};
+		let info = sysGPathInfo {
+			num_points,
+			points: points_pointer,
+		};
+		match unsafe { gpath_create(&info) } {
+			Some(raw_path) => Ok(Self(Handle::new(raw_path), points)),
+			None => Err(points),
+		}
+	}
+
+	/// Creates a path through `points`, which are kept alive along with it.
+	///
+	/// # Errors
+	///
+	/// Iff the path couldn't be created, in which case `points` is returned.
+	pub fn from_box(points: Box<'static, [GPoint]>) -> Result<Self, Box<'static, [GPoint]>> {
+		Self::new(PathPoints::Owned(points)).map_err(|points| match points {
+			PathPoints::Owned(points) => points,
+			PathPoints::Static(_) => unreachable!(),
+		})
+	}
+
+	/// Creates a path through `points`.
+	///
+	/// # Errors
+	///
+	/// Iff the path couldn't be created.
+	pub fn from_static(points: &'static [GPoint]) -> Result<Self, ()> {
+		Self::new(PathPoints::Static(points)).ok().ok_or(())
+	}
+
+	/// Sets the offset the points are drawn relative to.
+	pub fn move_to(&self, point: GPoint) {
+		unsafe { gpath_move_to(self.0.as_mut_unchecked(), point) }
+	}
+
+	/// Sets the rotation around the origin of the points (before the offset is applied), clockwise.
+	pub fn rotate_to(&self, angle: Angle) {
+		unsafe { gpath_rotate_to(self.0.as_mut_unchecked(), angle.0) }
+	}
+
+	/// Fills the area enclosed by the path in the current fill color.
+	pub fn draw_filled(&self, ctx: &mut GContext) {
+		unsafe { gpath_draw_filled(&mut *ctx.0, self.0.as_mut_unchecked()) }
+	}
+
+	/// Draws the path, including the segment from the last back to the first point, in the current stroke color.
+	pub fn draw_outline(&self, ctx: &mut GContext) {
+		unsafe { gpath_draw_outline(&mut *ctx.0, self.0.as_mut_unchecked()) }
+	}
+
+	/// Like [`.draw_outline(…)`](#method.draw_outline), but without closing the path.
+	pub fn draw_outline_open(&self, ctx: &mut GContext) {
+		unsafe { gpath_draw_outline_open(&mut *ctx.0, self.0.as_mut_unchecked()) }
+	}
+}
+
+impl Drop for Path {
+	fn drop(&mut self) {
+		unsafe { gpath_destroy(self.0.duplicate().unwrap()) }
+		// The points are dropped after this, as field.
+	}
+}
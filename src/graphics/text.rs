@@ -0,0 +1,33 @@
+//! Text layout, for measuring text before drawing it.
+
+use super::{
+	fonts::Font,
+	graphics_types::{GRect, GSize},
+};
+use crate::standard_c::{CStr, Storage};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::graphics::text::*;
+
+pub use pebble_sys::graphics::text::{GTextAlignment, GTextOverflowMode};
+
+/// The size `text` takes up when drawn with `font` into `bounds`, as by [`GContext::draw_text`].
+///
+/// [`GContext::draw_text`]: ../graphics_context/struct.GContext.html#method.draw_text
+#[must_use]
+pub fn size(
+	text: &CStr<impl Storage>,
+	font: &Font,
+	bounds: GRect,
+	overflow_mode: GTextOverflowMode,
+	alignment: GTextAlignment,
+) -> GSize {
+	unsafe {
+		graphics_text_layout_get_content_size(
+			text.as_c_str(),
+			&*font.0,
+			bounds,
+			overflow_mode,
+			alignment,
+		)
+	}
+}
@@ -1,6 +1,22 @@
 //! Documentation for this crate is work in progress.
 //!
 //! For now, please also refer to the C API documentation at <https://developer.rebble.io/developer.pebble.com/docs/c/index.html> for more information.
+//!
+//! # Features
+//!
+//! `"app"` (default): Adds [`graphics`], [`user_interface`] and other APIs that are only available to foreground apps,
+//! like [`foundation::wakeup`] and [`foundation::launch_reason`].
+//! Background workers must depend on this crate with `default-features = false` (and no other dependency of theirs may enable `"app"`),
+//! since the worker binary can't link against these APIs.
+//!
+//! `"worker"`: Adds the [`worker`] entry point module for background workers.
+//!
+//! `"reference-trig"`: Computes [`foundation::math`]'s trigonometry in Rust instead of calling the SDK.
+//! The results match the SDK's lookup tables, so layout math can be unit-tested on the host.
+//!
+//! # Platforms
+//!
+//! The target platform isn't a feature, but selected with `--cfg pebble_platform="…"`. See [`platform`] for details.
 
 #![no_std]
 #![feature(coerce_unsized)]
@@ -8,6 +24,7 @@
 #![feature(maybe_uninit_extra)]
 #![feature(maybe_uninit_ref)]
 #![feature(maybe_uninit_slice)]
+#![feature(min_const_generics)]
 #![feature(min_specialization)]
 #![feature(never_type)]
 #![feature(unsize)]
@@ -17,15 +34,18 @@
 #![allow(clippy::module_name_repetitions)] // Matching the SDK documentation.
 
 use core::{
+	cell::UnsafeCell,
 	future::Future,
 	intrinsics::drop_in_place,
 	marker::{PhantomData, Unsize},
-	mem::{size_of_val_raw, ManuallyDrop, MaybeUninit},
+	mem::{self, size_of_val_raw, ManuallyDrop, MaybeUninit},
 	ops::{CoerceUnsized, Deref, DerefMut},
 	pin::Pin,
-	str,
+	ptr::{self, NonNull},
+	slice, str,
 	task::{Context, Poll},
 };
+use foundation::event_service::SubscriptionError;
 use pebble_sys::standard_c::memory::free;
 use standard_c::{
 	memory::{calloc, malloc, malloc_buffer_uninit, memcpy_uninit},
@@ -33,9 +53,14 @@ use standard_c::{
 };
 
 pub mod foundation;
+#[cfg(feature = "app")]
 pub mod graphics;
+pub mod platform;
 pub mod standard_c;
+#[cfg(feature = "app")]
 pub mod user_interface;
+#[cfg(feature = "worker")]
+pub mod worker;
 
 trait SpecialDrop {
 	fn special_drop(&mut self);
@@ -87,6 +112,40 @@ impl<'a> Box<'a, [MaybeUninit<u8>]> {
 	}
 }
 
+impl<'a, T> Box<'a, [T]> {
+	/// Creates an empty boxed slice without allocating.
+	#[must_use]
+	pub fn empty() -> Self {
+		unsafe { Box::from_raw(slice::from_raw_parts_mut(NonNull::dangling().as_ptr(), 0)) }
+	}
+
+	/// Appends `value` to the slice, which moves it into a new, larger heap allocation.
+	///
+	/// # Errors
+	///
+	/// Iff the heap allocation fails, in which case `value` is returned and the slice is unchanged.
+	pub fn push(r#box: &mut Self, value: T) -> Result<(), T> {
+		let len = r#box.len();
+		let mem = match calloc::<T>(len + 1) {
+			Ok(mem) => mem,
+			Err(()) => return Err(value),
+		};
+		unsafe {
+			ptr::copy_nonoverlapping(r#box.as_ptr(), mem.as_mut_ptr() as *mut T, len);
+			mem[len].write(value);
+			let old = Box::leak(mem::replace(
+				r#box,
+				Box::from_raw(MaybeUninit::slice_assume_init_mut(mem)),
+			));
+			// The elements were moved, so only the old allocation is released.
+			if mem::size_of_val(old) != 0 {
+				free(&mut *(old as *mut [T] as *mut _));
+			}
+		}
+		Ok(())
+	}
+}
+
 impl<'a, T: ?Sized> Drop for Box<'a, T> {
 	fn drop(&mut self) {
 		unsafe {
@@ -212,3 +271,71 @@ impl<'a, T: ?Sized> DerefMut for Handle<'a, T> {
 		unsafe { &mut *self.0 }
 	}
 }
+
+/// Storage for the closure behind a context-less SDK callback.
+///
+/// Pebble apps are single-threaded and handlers are only called from the event loop, so there's no synchronisation here.
+///
+/// The closure is moved out of the slot while it runs, so it can end its own subscription (or start a new one) without being dropped mid-call.
+struct HandlerSlot<T: ?Sized + 'static>(UnsafeCell<HandlerSlotState<T>>);
+
+enum HandlerSlotState<T: ?Sized + 'static> {
+	Vacant,
+	Occupied(Box<'static, T>),
+	/// The handler is currently executing and still subscribed.
+	Running,
+}
+
+unsafe impl<T: ?Sized + 'static> Sync for HandlerSlot<T> {}
+
+impl<T: ?Sized + 'static> HandlerSlot<T> {
+	const fn new() -> Self {
+		Self(UnsafeCell::new(HandlerSlotState::Vacant))
+	}
+
+	fn is_occupied(&self) -> bool {
+		!matches!(unsafe { &*self.0.get() }, HandlerSlotState::Vacant)
+	}
+
+	/// Moves `handler` onto the heap and stores it in this slot.
+	///
+	/// # Errors
+	///
+	/// Iff the slot is already occupied or the heap allocation fails.
+	fn fill<F: 'static + Unsize<T>>(&self, handler: F) -> Result<(), SubscriptionError<F>> {
+		if self.is_occupied() {
+			return Err(SubscriptionError::AlreadySubscribed(handler));
+		}
+		let handler = Box::new(handler).map_err(SubscriptionError::OutOfMemory)?;
+		unsafe { *self.0.get() = HandlerSlotState::Occupied(handler) };
+		Ok(())
+	}
+
+	/// Empties this slot.
+	///
+	/// If the handler is currently running, it's instead dropped by [`call`](`HandlerSlot::call`) once it returns.
+	fn take(&self) -> Option<Box<'static, T>> {
+		match unsafe { mem::replace(&mut *self.0.get(), HandlerSlotState::Vacant) } {
+			HandlerSlotState::Occupied(handler) => Some(handler),
+			HandlerSlotState::Vacant | HandlerSlotState::Running => None,
+		}
+	}
+
+	/// Runs `call` with the stored handler, unless the slot is empty or its handler is already running.
+	fn call(&self, call: impl FnOnce(&mut T)) {
+		let mut handler =
+			match unsafe { mem::replace(&mut *self.0.get(), HandlerSlotState::Running) } {
+				HandlerSlotState::Occupied(handler) => handler,
+				state => {
+					unsafe { *self.0.get() = state };
+					return;
+				}
+			};
+		call(&mut handler);
+		// If the subscription ended (or was replaced) during the call, `handler` is dropped here instead.
+		let state = unsafe { &mut *self.0.get() };
+		if let HandlerSlotState::Running = state {
+			*state = HandlerSlotState::Occupied(handler);
+		}
+	}
+}
@@ -0,0 +1,123 @@
+//! Compile-time platform capabilities, replacing the SDK's `PBL_*` preprocessor macros.
+//!
+//! The platform is selected with a `pebble_platform` configuration option instead of a Cargo feature,
+//! since features are unified across the dependency graph and two crates could otherwise select different platforms.
+//! Pass it to every crate in the build through `RUSTFLAGS`, for example:
+//!
+//! ```sh
+//! RUSTFLAGS='--cfg pebble_platform="chalk"' cargo build --target thumbv7m-pebble-eabi
+//! ```
+//!
+//! Valid values are `"aplite"`, `"basalt"`, `"chalk"`, `"diorite"` and `"emery"`, and at most one may be set.
+//! Code that can't run on some platforms can be left out with `#[cfg(pebble_platform = "…")]`,
+//! or branch on the constants below in ordinary `if` conditions.
+//!
+//! Without `pebble_platform`, [`PBL_PLATFORM`] is [`None`] and the constants describe a black and white rectangular watch without optional hardware.
+
+#[cfg(any(
+	all(
+		pebble_platform = "aplite",
+		any(
+			pebble_platform = "basalt",
+			pebble_platform = "chalk",
+			pebble_platform = "diorite",
+			pebble_platform = "emery"
+		)
+	),
+	all(
+		pebble_platform = "basalt",
+		any(
+			pebble_platform = "chalk",
+			pebble_platform = "diorite",
+			pebble_platform = "emery"
+		)
+	),
+	all(
+		pebble_platform = "chalk",
+		any(pebble_platform = "diorite", pebble_platform = "emery")
+	),
+	all(pebble_platform = "diorite", pebble_platform = "emery"),
+))]
+compile_error!("At most one `pebble_platform` may be set.");
+
+/// A watch platform, as in the SDK's `targetPlatforms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+	/// Pebble and Pebble Steel.
+	Aplite,
+	/// Pebble Time and Pebble Time Steel.
+	Basalt,
+	/// Pebble Time Round.
+	Chalk,
+	/// Pebble 2.
+	Diorite,
+	/// Pebble Time 2.
+	Emery,
+}
+
+impl Platform {
+	/// The display width in pixels.
+	#[must_use]
+	pub const fn display_width(self) -> i16 {
+		match self {
+			Platform::Aplite | Platform::Basalt | Platform::Diorite => 144,
+			Platform::Chalk => 180,
+			Platform::Emery => 200,
+		}
+	}
+
+	/// The display height in pixels.
+	#[must_use]
+	pub const fn display_height(self) -> i16 {
+		match self {
+			Platform::Aplite | Platform::Basalt | Platform::Diorite => 168,
+			Platform::Chalk => 180,
+			Platform::Emery => 228,
+		}
+	}
+}
+
+/// The platform selected through `--cfg pebble_platform="…"`, if any.
+pub const PBL_PLATFORM: Option<Platform> = if cfg!(pebble_platform = "aplite") {
+	Some(Platform::Aplite)
+} else if cfg!(pebble_platform = "basalt") {
+	Some(Platform::Basalt)
+} else if cfg!(pebble_platform = "chalk") {
+	Some(Platform::Chalk)
+} else if cfg!(pebble_platform = "diorite") {
+	Some(Platform::Diorite)
+} else if cfg!(pebble_platform = "emery") {
+	Some(Platform::Emery)
+} else {
+	None
+};
+
+/// The display shows 64 colors.
+pub const PBL_COLOR: bool = cfg!(any(
+	pebble_platform = "basalt",
+	pebble_platform = "chalk",
+	pebble_platform = "emery"
+));
+
+/// The display is black and white.
+pub const PBL_BW: bool = !PBL_COLOR;
+
+/// The display is round.
+pub const PBL_ROUND: bool = cfg!(pebble_platform = "chalk");
+
+/// The display is rectangular.
+pub const PBL_RECT: bool = !PBL_ROUND;
+
+/// The health service is available, though the user may still have disabled it.
+pub const PBL_HEALTH: bool = cfg!(any(
+	pebble_platform = "basalt",
+	pebble_platform = "chalk",
+	pebble_platform = "diorite",
+	pebble_platform = "emery"
+));
+
+/// Dictation is available, if a phone is connected.
+pub const PBL_MICROPHONE: bool = PBL_HEALTH;
+
+/// The smartstrap port is available.
+pub const PBL_SMARTSTRAP: bool = PBL_HEALTH;
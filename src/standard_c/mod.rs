@@ -200,3 +200,88 @@ impl CStr<Static> {
 		CStr::<Static>::from_zero_terminated_unchecked(str)
 	}
 }
+
+/// A fixed-capacity buffer that SDK functions can write zero-terminated text into.
+///
+/// `N` includes the terminating `'\0'`, so at most `N - 1` bytes of text fit.
+/// Dereferences to the current text, without the terminator.
+pub struct CStrBuf<const N: usize> {
+	buffer: [u8; N],
+	len: usize,
+}
+
+impl<const N: usize> CStrBuf<N> {
+	/// The maximum text length in bytes, without the terminator.
+	///
+	/// This underflows for `N == 0`, which makes [`new`](#method.new) fail to compile, as there would be no room for the terminator.
+	pub const CAPACITY: usize = N - 1;
+
+	/// Creates an empty buffer.
+	#[must_use]
+	pub fn new() -> Self {
+		// Evaluating the constant rejects `N == 0` at compile time.
+		let _ = Self::CAPACITY;
+		Self {
+			buffer: [0; N],
+			len: 0,
+		}
+	}
+
+	/// The maximum text length in bytes, without the terminator.
+	#[must_use]
+	#[allow(clippy::unused_self)] // Like other collections' `capacity`.
+	pub fn capacity(&self) -> usize {
+		Self::CAPACITY
+	}
+
+	pub fn clear(&mut self) {
+		self.buffer[0] = 0;
+		self.len = 0;
+	}
+
+	#[must_use]
+	pub fn as_c_str(&self) -> &CStr<Stack> {
+		unsafe {
+			//SAFETY: Only valid UTF-8 is kept before the terminator, see `fill`.
+			CStr::from_zero_terminated_unchecked(str::from_utf8_unchecked(
+				&self.buffer[..=self.len],
+			))
+		}
+	}
+
+	/// Lets `write` fill the buffer as zero-terminated string, given its start and full size.
+	///
+	/// The text is cut off at the first `'\0'` (or the end of the buffer) and before any invalid UTF-8.
+	///
+	/// # Safety
+	///
+	/// `write` must not write outside the `N` bytes it's given.
+	pub(crate) unsafe fn fill(&mut self, write: impl FnOnce(&mut c_str, usize)) {
+		self.clear();
+		write(&mut *(self.buffer.as_mut_ptr() as *mut c_str), N);
+		let len = self.buffer[..Self::CAPACITY]
+			.iter()
+			.position(|&b| b == 0)
+			.unwrap_or(Self::CAPACITY);
+		let len = match str::from_utf8(&self.buffer[..len]) {
+			Ok(_) => len,
+			Err(error) => error.valid_up_to(),
+		};
+		self.buffer[len] = 0;
+		self.len = len;
+	}
+}
+
+impl<const N: usize> Default for CStrBuf<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const N: usize> Deref for CStrBuf<N> {
+	type Target = str;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { str::from_utf8_unchecked(&self.buffer[..self.len]) }
+	}
+}
@@ -0,0 +1,215 @@
+//! A vertical bar of up to three icons along the right edge of a window, one per button.
+
+use super::{
+	clicks::ButtonId,
+	layers::LayerRef,
+	window::{self, Window},
+};
+use crate::{
+	graphics::{graphics_types::Color8, Bitmap},
+	Box, Handle,
+};
+use core::{cell::Cell, marker::PhantomData, ptr::NonNull};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	standard_c::memory::void,
+	user_interface::{
+		action_bar_layer::{
+			ActionBarLayer as sysActionBarLayer,
+			ActionBarLayerIconPressAnimation as sysActionBarLayerIconPressAnimation, *,
+		},
+		clicks::{click_recognizer_get_button_id, ClickRecognizerRef},
+		window::{window_single_click_subscribe, Window as sysWindow},
+	},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+pub type ActionBarLayerIconPressAnimation = sysActionBarLayerIconPressAnimation;
+
+type ClickHandler<'a, T> = Option<Box<'a, dyn 'a + FnMut(&mut T)>>;
+
+struct ActionBarData<'a, T> {
+	up: ClickHandler<'a, T>,
+	select: ClickHandler<'a, T>,
+	down: ClickHandler<'a, T>,
+	/// The window this action bar is currently attached to.
+	raw_window: Option<NonNull<sysWindow>>,
+}
+
+/// An action bar for a [`Window<T>`], whose click handlers receive that window's user data.
+///
+/// Icons and the window are borrowed for `'a`, so they can't be destroyed while the action bar still uses them.
+///
+/// [`Window<T>`]: ../window/struct.Window.html
+pub struct ActionBarLayer<'a, T>(
+	Handle<'static, sysActionBarLayer>,
+	Box<'a, ActionBarData<'a, T>>,
+	/// `'a` is invariant so that icons can't be attached through a shortened lifetime.
+	PhantomData<Cell<&'a Bitmap>>,
+);
+
+impl<'a, T> ActionBarLayer<'a, T> {
+	/// # Errors
+	///
+	/// Iff the associated data can't be moved onto the heap or the action bar can't be created.
+	pub fn new() -> Result<Self, ()> {
+		#![allow(clippy::items_after_statements)]
+
+		let mut data = Box::new(ActionBarData {
+			up: None,
+			select: None,
+			down: None,
+			raw_window: None,
+		})
+		.ok()
+		.ok_or(())?;
+
+		extern "C" fn raw_click<T>(recognizer: ClickRecognizerRef, context: *mut void) {
+			let data = unsafe { &mut *context.cast::<ActionBarData<T>>() };
+			let handler = match unsafe { click_recognizer_get_button_id(recognizer) } {
+				ButtonId::Up => &mut data.up,
+				ButtonId::Select => &mut data.select,
+				ButtonId::Down => &mut data.down,
+				ButtonId::Back => return,
+			};
+			if let Some(handler) = handler.as_mut() {
+				unsafe {
+					//SAFETY: `raw_window` is only set by `.attach_to_window(…)`, which requires a `Window<T>` that's borrowed for `'a`.
+					window::with_user_data(data.raw_window.unsafe_unwrap(), |user_data: &mut T| {
+						handler(user_data)
+					})
+				}
+			}
+		}
+		extern "C" fn raw_click_config_provider<T>(_context: *mut void) {
+			for button in [ButtonId::Up, ButtonId::Select, ButtonId::Down]
+				.iter()
+				.copied()
+			{
+				unsafe { window_single_click_subscribe(button, raw_click::<T>) }
+			}
+		}
+
+		match unsafe { action_bar_layer_create() } {
+			Some(raw_action_bar) => {
+				unsafe {
+					//SAFETY: `data` is only dropped after the action bar is destroyed.
+					action_bar_layer_set_context(
+						raw_action_bar,
+						&mut *data as *mut ActionBarData<T> as *mut void,
+					);
+					action_bar_layer_set_click_config_provider(
+						raw_action_bar,
+						Some(raw_click_config_provider::<T>),
+					)
+				}
+				Ok(Self(Handle::new(raw_action_bar), data, PhantomData))
+			}
+			None => Err(()),
+		}
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { action_bar_layer_get_layer(&*self.0) }))
+	}
+
+	/// Sets the handler for single clicks on `button`, replacing any previous one.
+	///
+	/// `handler` receives the user data of the window this action bar is attached to, and is only called while that window is loaded.
+	/// Window handlers that `handler` causes to run, for example by popping that window, are deferred until it returns.
+	///
+	/// # Errors
+	///
+	/// Iff `button` is [`ButtonId::Back`] or `handler` can't be moved onto the heap, in which case `handler` is returned.
+	pub fn set_click_handler<F: 'a + FnMut(&mut T)>(
+		&mut self,
+		button: ButtonId,
+		handler: F,
+	) -> Result<(), F> {
+		let slot = match button {
+			ButtonId::Up => &mut self.1.up,
+			ButtonId::Select => &mut self.1.select,
+			ButtonId::Down => &mut self.1.down,
+			ButtonId::Back => return Err(handler),
+		};
+		*slot = Some(Box::new(handler)?);
+		Ok(())
+	}
+
+	/// Removes the handler for single clicks on `button`, if any.
+	pub fn clear_click_handler(&mut self, button: ButtonId) {
+		match button {
+			ButtonId::Up => self.1.up = None,
+			ButtonId::Select => self.1.select = None,
+			ButtonId::Down => self.1.down = None,
+			ButtonId::Back => (),
+		}
+	}
+
+	/// Adds this action bar to `window` and routes `window`'s UP, SELECT and DOWN clicks to it.
+	///
+	/// The action bar is removed from `window` again when dropped or attached to another window,
+	/// so `window` may stay on screen afterwards. `window` stays borrowed until then.
+	pub fn attach_to_window(&mut self, window: &'a Window<T>) {
+		self.detach_from_window();
+		let raw_window = unsafe { window.0.as_mut_unchecked() };
+		self.1.raw_window = Some(NonNull::from(&mut *raw_window));
+		unsafe { action_bar_layer_add_to_window(self.0.as_mut_unchecked(), raw_window) }
+	}
+
+	/// Removes this action bar from its window, along with the window's click configuration.
+	pub fn detach_from_window(&mut self) {
+		if self.1.raw_window.take().is_some() {
+			//SAFETY: The window is borrowed for `'a`, so it's still alive.
+			unsafe { action_bar_layer_remove_from_window(self.0.as_mut_unchecked()) }
+		}
+	}
+
+	pub fn set_icon(&self, button: ButtonId, icon: &'a Bitmap) {
+		unsafe { action_bar_layer_set_icon(self.0.as_mut_unchecked(), button, &*icon.0) }
+	}
+
+	/// Like [`.set_icon(…)`](#method.set_icon), but optionally animates the change.
+	pub fn set_icon_animated(&self, button: ButtonId, icon: &'a Bitmap, animated: bool) {
+		unsafe {
+			action_bar_layer_set_icon_animated(
+				self.0.as_mut_unchecked(),
+				button,
+				&*icon.0,
+				animated,
+			)
+		}
+	}
+
+	pub fn clear_icon(&self, button: ButtonId) {
+		unsafe { action_bar_layer_clear_icon(self.0.as_mut_unchecked(), button) }
+	}
+
+	/// Sets how the icon for `button` reacts to presses.
+	pub fn set_icon_press_animation(
+		&self,
+		button: ButtonId,
+		animation: ActionBarLayerIconPressAnimation,
+	) {
+		unsafe {
+			action_bar_layer_set_icon_press_animation(self.0.as_mut_unchecked(), button, animation)
+		}
+	}
+
+	pub fn set_background_color(&self, background_color: Color8) {
+		unsafe {
+			action_bar_layer_set_background_color(self.0.as_mut_unchecked(), background_color)
+		}
+	}
+}
+
+impl<'a, T> Drop for ActionBarLayer<'a, T> {
+	fn drop(&mut self) {
+		self.detach_from_window();
+		unsafe {
+			// The data is dropped after this, as field.
+			action_bar_layer_destroy(self.0.duplicate().unwrap())
+		}
+	}
+}
@@ -0,0 +1,420 @@
+//! A full screen menu of actions, optionally nested into several levels. Requires SDK 3 or later.
+//!
+//! Menus are described as tree of [`ActionMenuLevel`]s, which is handed over to the system by [`ActionMenuConfig::open`].
+//! The tree is freed automatically once the menu has closed.
+//!
+//! [`ActionMenuLevel`]: ./struct.ActionMenuLevel.html
+//! [`ActionMenuConfig::open`]: ./struct.ActionMenuConfig.html#method.open
+
+use super::window::Window;
+use crate::{
+	graphics::graphics_types::Color8,
+	standard_c::{CStr, NotStack},
+	Box, Handle,
+};
+use core::{
+	convert::TryInto,
+	ptr::{self, NonNull},
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	standard_c::memory::{c_str, void},
+	user_interface::action_menu::{
+		ActionMenu as sysActionMenu, ActionMenuAlign as sysActionMenuAlign,
+		ActionMenuConfig as sysActionMenuConfig, ActionMenuItem as sysActionMenuItem,
+		ActionMenuLevel as sysActionMenuLevel,
+		ActionMenuLevelDisplayMode as sysActionMenuLevelDisplayMode, *,
+	},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+pub type ActionMenuAlign = sysActionMenuAlign;
+
+pub type ActionMenuLevelDisplayMode = sysActionMenuLevelDisplayMode;
+
+struct ActionMenuItem<T: 'static> {
+	action_data: T,
+	perform: Box<'static, dyn FnMut(&ActionMenuRef, &mut T)>,
+}
+
+enum ActionMenuEntry<T: 'static> {
+	Action {
+		label: &'static c_str,
+		item: Box<'static, ActionMenuItem<T>>,
+	},
+	Child {
+		label: &'static c_str,
+		level: ActionMenuLevel<T>,
+	},
+}
+
+/// One level of an action menu, listing actions and nested levels.
+///
+/// Heap allocation failures are remembered and reported by [`ActionMenuConfig::open`].
+///
+/// [`ActionMenuConfig::open`]: ./struct.ActionMenuConfig.html#method.open
+#[must_use]
+pub struct ActionMenuLevel<T: 'static> {
+	entries: Box<'static, [ActionMenuEntry<T>]>,
+	display_mode: ActionMenuLevelDisplayMode,
+	out_of_memory: bool,
+}
+
+/// Everything needed to open an action menu.
+#[must_use]
+pub struct ActionMenuConfig<T: 'static> {
+	root_level: ActionMenuLevel<T>,
+	background_color: Color8,
+	foreground_color: Color8,
+	align: ActionMenuAlign,
+}
+
+/// Owned by the system while the menu is open.
+struct ActionMenuData<T: 'static> {
+	root_level: ActionMenuLevel<T>,
+	raw_root_level: Option<NonNull<sysActionMenuLevel>>,
+	/// [`None`] once the menu has closed.
+	raw_menu: Option<NonNull<sysActionMenu>>,
+	/// Whether the matching [`ActionMenu`] still exists, in which case it frees this data instead.
+	handle_alive: bool,
+	/// Whether a `perform` closure is running, in which case freeing the items is delayed until it returns.
+	performing: bool,
+}
+
+/// A handle to an open action menu.
+///
+/// Dropping this handle doesn't close the menu.
+pub struct ActionMenu<T: 'static>(NonNull<ActionMenuData<T>>);
+
+/// The open action menu an action is performed in.
+#[repr(transparent)]
+pub struct ActionMenuRef<'a>(Handle<'a, sysActionMenu>);
+
+impl<T: 'static> ActionMenuLevel<T> {
+	pub fn new() -> Self {
+		Self {
+			entries: Box::empty(),
+			display_mode: ActionMenuLevelDisplayMode::Wide,
+			out_of_memory: false,
+		}
+	}
+
+	pub fn display_mode(mut self, display_mode: ActionMenuLevelDisplayMode) -> Self {
+		self.display_mode = display_mode;
+		self
+	}
+
+	/// Adds an action labelled `label`.
+	///
+	/// When the action is selected, `perform` is called with the menu and `action_data`.
+	/// The menu then closes unless it's [frozen] before `perform` returns.
+	///
+	/// [frozen]: ./struct.ActionMenuRef.html#method.freeze
+	pub fn action<F: 'static + FnMut(&ActionMenuRef, &mut T)>(
+		mut self,
+		label: &'static CStr<impl NotStack>,
+		action_data: T,
+		perform: F,
+	) -> Self {
+		let item = Box::new(perform).ok().and_then(|perform| {
+			Box::new(ActionMenuItem {
+				action_data,
+				perform,
+			})
+			.ok()
+		});
+		match item {
+			Some(item) => self.push(ActionMenuEntry::Action {
+				label: label.as_c_str(),
+				item,
+			}),
+			None => self.out_of_memory = true,
+		}
+		self
+	}
+
+	/// Adds a nested level, shown when the entry labelled `label` is selected.
+	pub fn child(mut self, label: &'static CStr<impl NotStack>, level: Self) -> Self {
+		self.push(ActionMenuEntry::Child {
+			label: label.as_c_str(),
+			level,
+		});
+		self
+	}
+
+	fn push(&mut self, entry: ActionMenuEntry<T>) {
+		if Box::push(&mut self.entries, entry).is_err() {
+			self.out_of_memory = true;
+		}
+	}
+
+	fn is_out_of_memory(&self) -> bool {
+		self.out_of_memory
+			|| self.entries.iter().any(|entry| match entry {
+				ActionMenuEntry::Action { .. } => false,
+				ActionMenuEntry::Child { level, .. } => level.is_out_of_memory(),
+			})
+	}
+
+	/// Creates the system's copy of this level and its children.
+	///
+	/// The returned level points into `self`, which must not be moved or dropped while it exists.
+	fn create_raw(&mut self) -> Result<&'static mut sysActionMenuLevel, ()> {
+		#![allow(clippy::items_after_statements)]
+
+		extern "C" fn raw_perform<T: 'static>(
+			raw_menu: &mut sysActionMenu,
+			raw_item: &sysActionMenuItem,
+			_context: *mut void,
+		) {
+			let data = unsafe { action_menu_get_context(raw_menu) }.cast::<ActionMenuData<T>>();
+			let item = unsafe {
+				action_menu_item_get_action_data(raw_item)
+					.cast::<ActionMenuItem<T>>()
+					.as_mut()
+					.unsafe_unwrap()
+			};
+			unsafe {
+				//SAFETY: `data` is a separate allocation from the items and outlives the open menu.
+				(*data).performing = true;
+				(item.perform)(&ActionMenuRef(Handle::new(raw_menu)), &mut item.action_data);
+				(*data).performing = false;
+				if (*data).raw_menu.is_none() {
+					// The menu was closed during `perform`.
+					ActionMenuData::free_closed(data)
+				}
+			}
+		}
+
+		let max_items = self.entries.len().try_into().ok().ok_or(())?;
+		let raw_level = unsafe { action_menu_level_create(max_items) }.ok_or(())?;
+		unsafe { action_menu_level_set_display_mode(raw_level, self.display_mode) };
+
+		let result = self.entries.iter_mut().try_for_each(|entry| match entry {
+			ActionMenuEntry::Action { label, item } => unsafe {
+				action_menu_level_add_action(
+					raw_level,
+					*label,
+					raw_perform::<T>,
+					&mut **item as *mut ActionMenuItem<T> as *mut void,
+				)
+			}
+			.map(|_| ())
+			.ok_or(()),
+			ActionMenuEntry::Child { label, level } => {
+				let raw_child = level.create_raw()?;
+				let raw_child_pointer = NonNull::from(&mut *raw_child);
+				if unsafe { action_menu_level_add_child(raw_level, raw_child, *label) }.is_some() {
+					Ok(())
+				} else {
+					unsafe { destroy_raw_hierarchy(raw_child_pointer) };
+					Err(())
+				}
+			}
+		});
+		match result {
+			Ok(()) => Ok(raw_level),
+			Err(()) => {
+				unsafe { destroy_raw_hierarchy(NonNull::from(raw_level)) };
+				Err(())
+			}
+		}
+	}
+}
+
+impl<T: 'static> Default for ActionMenuLevel<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// # Safety
+///
+/// `raw_level` must be a root level that isn't used afterwards.
+unsafe fn destroy_raw_hierarchy(raw_level: NonNull<sysActionMenuLevel>) {
+	// The action data is owned by the Rust side of the tree, so there's nothing to free per item.
+	action_menu_hierarchy_destroy(raw_level.as_ref(), None, ptr::null_mut::<u8>() as *mut void)
+}
+
+impl<T: 'static> ActionMenuConfig<T> {
+	pub fn new(
+		root_level: ActionMenuLevel<T>,
+		background_color: Color8,
+		foreground_color: Color8,
+	) -> Self {
+		Self {
+			root_level,
+			background_color,
+			foreground_color,
+			align: ActionMenuAlign::Top,
+		}
+	}
+
+	pub fn align(mut self, align: ActionMenuAlign) -> Self {
+		self.align = align;
+		self
+	}
+
+	/// Opens the action menu on top of the current window.
+	///
+	/// # Errors
+	///
+	/// Iff any heap allocation failed (also while building the levels) or the menu couldn't be opened.
+	pub fn open(self) -> Result<ActionMenu<T>, ()> {
+		#![allow(clippy::items_after_statements)]
+
+		if self.root_level.is_out_of_memory() {
+			return Err(());
+		}
+
+		let data = Box::leak(
+			Box::new(ActionMenuData {
+				root_level: self.root_level,
+				raw_root_level: None,
+				raw_menu: None,
+				handle_alive: true,
+				performing: false,
+			})
+			.ok()
+			.ok_or(())?,
+		);
+		let data_pointer = NonNull::from(&mut *data);
+		let free_data = || unsafe {
+			drop(Box::<ActionMenuData<T>>::from_raw(
+				&mut *data_pointer.as_ptr(),
+			))
+		};
+
+		let raw_root_level = match data.root_level.create_raw() {
+			Ok(raw_root_level) => raw_root_level,
+			Err(()) => {
+				free_data();
+				return Err(());
+			}
+		};
+		data.raw_root_level = Some(NonNull::from(&mut *raw_root_level));
+
+		extern "C" fn raw_did_close<T: 'static>(
+			_raw_menu: &mut sysActionMenu,
+			_performed_action: Option<&sysActionMenuItem>,
+			context: *mut void,
+		) {
+			unsafe {
+				let data = context.cast::<ActionMenuData<T>>();
+				(*data).raw_menu = None;
+				if !(*data).performing {
+					ActionMenuData::free_closed(data)
+				}
+			}
+		}
+
+		let mut raw_config = sysActionMenuConfig {
+			root_level: raw_root_level,
+			context: data_pointer.as_ptr() as *mut void,
+			colors: ActionMenuColors {
+				background: self.background_color,
+				foreground: self.foreground_color,
+			},
+			will_close: None,
+			did_close: Some(raw_did_close::<T>),
+			align: self.align,
+		};
+		let raw_menu = if let Some(raw_menu) = unsafe { action_menu_open(&mut raw_config) } {
+			raw_menu
+		} else {
+			unsafe { destroy_raw_hierarchy(data.raw_root_level.take().unsafe_unwrap()) };
+			free_data();
+			return Err(());
+		};
+		data.raw_menu = Some(NonNull::from(raw_menu));
+		Ok(ActionMenu(data_pointer))
+	}
+}
+
+impl<T: 'static> ActionMenuData<T> {
+	/// Frees the items of a closed menu, along with `data` itself if the [`ActionMenu`] handle is gone.
+	///
+	/// # Safety
+	///
+	/// `data` must belong to a closed menu and not be used afterwards, unless the handle is still alive.
+	unsafe fn free_closed(data: *mut Self) {
+		let data = &mut *data;
+		destroy_raw_hierarchy(data.raw_root_level.take().unsafe_unwrap());
+		if data.handle_alive {
+			// Free the items now, the rest goes with the handle.
+			data.root_level = ActionMenuLevel::new();
+		} else {
+			drop(Box::<Self>::from_raw(data));
+		}
+	}
+}
+
+impl<T: 'static> ActionMenu<T> {
+	fn raw_menu(&self) -> Option<ActionMenuRef<'_>> {
+		unsafe { self.0.as_ref() }
+			.raw_menu
+			.map(|raw_menu| ActionMenuRef(Handle::new(unsafe { &mut *raw_menu.as_ptr() })))
+	}
+
+	/// Whether the menu hasn't closed yet.
+	#[must_use]
+	pub fn is_open(&self) -> bool {
+		self.raw_menu().is_some()
+	}
+
+	/// Closes the menu, if it's still open.
+	pub fn close(&self, animated: bool) {
+		if let Some(menu) = self.raw_menu() {
+			menu.close(animated)
+		}
+	}
+
+	/// Keeps the menu open and stops it from reacting to input, if it's still open.
+	pub fn freeze(&self) {
+		if let Some(menu) = self.raw_menu() {
+			menu.freeze()
+		}
+	}
+
+	pub fn unfreeze(&self) {
+		if let Some(menu) = self.raw_menu() {
+			menu.unfreeze()
+		}
+	}
+}
+
+impl<T: 'static> Drop for ActionMenu<T> {
+	fn drop(&mut self) {
+		let data = unsafe { self.0.as_mut() };
+		if data.raw_menu.is_some() || data.performing {
+			// Freed once the menu closes.
+			data.handle_alive = false;
+		} else {
+			unsafe { drop(Box::<ActionMenuData<T>>::from_raw(data)) }
+		}
+	}
+}
+
+impl<'a> ActionMenuRef<'a> {
+	pub fn close(&self, animated: bool) {
+		unsafe { action_menu_close(self.0.as_mut_unchecked(), animated) }
+	}
+
+	/// Keeps the menu open and stops it from reacting to input, for example while an action is carried out asynchronously.
+	pub fn freeze(&self) {
+		unsafe { action_menu_freeze(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn unfreeze(&self) {
+		unsafe { action_menu_unfreeze(self.0.as_mut_unchecked()) }
+	}
+
+	/// Shows `window` instead of returning to the previous one once the menu closes.
+	///
+	/// The menu can stay open after its [`ActionMenu`] handle is dropped, so `window` is borrowed for `'static`.
+	pub fn set_result_window<T: ?Sized>(&self, window: &'static Window<T>) {
+		unsafe {
+			action_menu_set_result_window(self.0.as_mut_unchecked(), window.0.as_mut_unchecked())
+		}
+	}
+}
@@ -0,0 +1,454 @@
+//! Timed animations, including animated layer frames and other properties.
+//!
+//! The system destroys an animation once it has finished playing or was unscheduled,
+//! so after that, setters have no effect and rescheduling fails.
+
+use super::layers::LayerRef;
+use crate::{
+	graphics::graphics_types::{GPoint, GRect, GSize},
+	Box,
+};
+use core::{
+	mem::ManuallyDrop,
+	ops::{Deref, DerefMut},
+	ptr::{self, NonNull},
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	standard_c::memory::void,
+	user_interface::animation::{
+		Animation as sysAnimation, AnimationCurve as sysAnimationCurve,
+		AnimationHandlers as sysAnimationHandlers,
+		AnimationImplementation as sysAnimationImplementation, *,
+	},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+/// Animation progress from `0` to [`ANIMATION_NORMALIZED_MAX`], after applying the curve.
+///
+/// [`ANIMATION_NORMALIZED_MAX`]: ./constant.ANIMATION_NORMALIZED_MAX.html
+pub type AnimationProgress = i32;
+
+pub const ANIMATION_NORMALIZED_MAX: AnimationProgress = 65535;
+
+/// Duration in milliseconds that makes an animation run until unscheduled.
+pub const ANIMATION_DURATION_INFINITE: u32 = u32::MAX;
+
+/// Play count that makes an animation repeat until unscheduled.
+pub const ANIMATION_PLAY_COUNT_INFINITE: u32 = u32::MAX;
+
+pub type AnimationCurve = sysAnimationCurve;
+
+struct AnimationData<'a> {
+	/// [`None`] once the system destroyed the animation.
+	raw_animation: Option<NonNull<sysAnimation>>,
+	started: Option<Box<'a, dyn 'a + FnMut()>>,
+	stopped: Option<Box<'a, dyn 'a + FnMut(bool)>>,
+	update: Option<Box<'a, dyn 'a + FnMut(AnimationProgress)>>,
+	/// Animations combined into this one. The system owns their raw animations along with this one.
+	components: Box<'a, [Animation<'a>]>,
+	/// Whether the system frees this data once the animation stops.
+	detached: bool,
+}
+
+/// An animation, unscheduled and destroyed when dropped unless [detached].
+///
+/// [detached]: #method.detach
+pub struct Animation<'a>(Box<'a, AnimationData<'a>>);
+
+/// An animation that changes a property (like a layer's frame) over time.
+pub struct PropertyAnimation<'a>(Animation<'a>);
+
+/// Values that can be animated by a [`PropertyAnimation`].
+///
+/// [`PropertyAnimation`]: ./struct.PropertyAnimation.html
+pub trait Interpolate: Copy {
+	/// The value at `progress` of the way from `from` to `to`.
+	fn interpolate(from: Self, to: Self, progress: AnimationProgress) -> Self;
+}
+
+impl Interpolate for i16 {
+	#[allow(clippy::cast_possible_truncation)] // Between `from` and `to` for normal curves.
+	fn interpolate(from: Self, to: Self, progress: AnimationProgress) -> Self {
+		(i32::from(from) + (i32::from(to) - i32::from(from)) * progress / ANIMATION_NORMALIZED_MAX)
+			as Self
+	}
+}
+
+impl Interpolate for u32 {
+	#[allow(clippy::cast_possible_truncation)] // Between `from` and `to` for normal curves.
+	#[allow(clippy::cast_sign_loss)]
+	fn interpolate(from: Self, to: Self, progress: AnimationProgress) -> Self {
+		(i64::from(from)
+			+ (i64::from(to) - i64::from(from)) * i64::from(progress)
+				/ i64::from(ANIMATION_NORMALIZED_MAX)) as Self
+	}
+}
+
+impl Interpolate for GPoint {
+	fn interpolate(from: Self, to: Self, progress: AnimationProgress) -> Self {
+		GPoint {
+			x: i16::interpolate(from.x, to.x, progress),
+			y: i16::interpolate(from.y, to.y, progress),
+		}
+	}
+}
+
+impl Interpolate for GSize {
+	fn interpolate(from: Self, to: Self, progress: AnimationProgress) -> Self {
+		GSize {
+			w: i16::interpolate(from.w, to.w, progress),
+			h: i16::interpolate(from.h, to.h, progress),
+		}
+	}
+}
+
+impl Interpolate for GRect {
+	fn interpolate(from: Self, to: Self, progress: AnimationProgress) -> Self {
+		GRect {
+			origin: GPoint::interpolate(from.origin, to.origin, progress),
+			size: GSize::interpolate(from.size, to.size, progress),
+		}
+	}
+}
+
+extern "C" fn raw_started(_raw_animation: &mut sysAnimation, context: *mut void) {
+	let data = unsafe { &mut *context.cast::<AnimationData>() };
+	if let Some(started) = data.started.as_mut() {
+		started()
+	}
+}
+
+extern "C" fn raw_stopped(_raw_animation: &mut sysAnimation, finished: bool, context: *mut void) {
+	let data = unsafe { &mut *context.cast::<AnimationData>() };
+	// The system destroys the animation right after this handler.
+	data.raw_animation = None;
+	if let Some(stopped) = data.stopped.as_mut() {
+		stopped(finished)
+	}
+	if data.detached {
+		unsafe { drop(Box::<AnimationData>::from_raw(data)) }
+	}
+}
+
+extern "C" fn raw_update(raw_animation: &mut sysAnimation, progress: AnimationProgress) {
+	let data = unsafe {
+		animation_get_context(raw_animation)
+			.cast::<AnimationData>()
+			.as_mut()
+			.unsafe_unwrap()
+	};
+	if let Some(update) = data.update.as_mut() {
+		update(progress)
+	}
+}
+
+static UPDATE_IMPLEMENTATION: sysAnimationImplementation = sysAnimationImplementation {
+	setup: None,
+	update: Some(raw_update),
+	teardown: None,
+};
+
+impl<'a> Animation<'a> {
+	/// Wraps a newly created raw animation, destroying it on failure.
+	fn from_raw(raw_animation: Option<&'static mut sysAnimation>) -> Result<Self, ()> {
+		let raw_animation = raw_animation.ok_or(())?;
+		let mut data = if let Ok(data) = Box::new(AnimationData {
+			raw_animation: Some(NonNull::from(&mut *raw_animation)),
+			started: None,
+			stopped: None,
+			update: None,
+			components: Box::empty(),
+			detached: false,
+		}) {
+			data
+		} else {
+			unsafe { animation_destroy(raw_animation) };
+			return Err(());
+		};
+		unsafe {
+			//SAFETY: `data` is only dropped after the animation is destroyed or, if detached, from the stopped handler.
+			animation_set_handlers(
+				raw_animation,
+				sysAnimationHandlers {
+					started: Some(raw_started),
+					stopped: Some(raw_stopped),
+				},
+				&mut *data as *mut AnimationData as *mut void,
+			)
+		};
+		Ok(Self(data))
+	}
+
+	/// Creates an animation that does nothing by itself, but can run handlers or act as delay in a [sequence].
+	///
+	/// [sequence]: #method.sequence
+	///
+	/// # Errors
+	///
+	/// Iff the animation can't be created.
+	pub fn new() -> Result<Self, ()> {
+		Self::from_raw(unsafe { animation_create() })
+	}
+
+	/// Creates an animation that calls `update` with the current progress for each frame.
+	///
+	/// # Errors
+	///
+	/// Iff `update` can't be moved onto the heap or the animation can't be created.
+	pub fn with_update<F: 'a + FnMut(AnimationProgress)>(update: F) -> Result<Self, ()> {
+		let update = Box::new(update).ok().ok_or(())?;
+		let mut animation = Self::new()?;
+		animation.0.update = Some(update);
+		unsafe {
+			animation_set_implementation(
+				animation.raw().unsafe_unwrap().as_mut(),
+				&UPDATE_IMPLEMENTATION,
+			)
+		};
+		Ok(animation)
+	}
+
+	/// Combines `animations` into one that plays them one after another.
+	///
+	/// # Errors
+	///
+	/// Iff any of `animations` was already destroyed, a heap allocation failed or the sequence can't be created.
+	pub fn sequence(animations: impl IntoIterator<Item = Self>) -> Result<Self, ()> {
+		Self::combine(animations, animation_sequence_create_from_array)
+	}
+
+	/// Combines `animations` into one that plays them all at the same time.
+	///
+	/// # Errors
+	///
+	/// Iff any of `animations` was already destroyed, a heap allocation failed or the spawn can't be created.
+	pub fn spawn(animations: impl IntoIterator<Item = Self>) -> Result<Self, ()> {
+		Self::combine(animations, animation_spawn_create_from_array)
+	}
+
+	fn combine(
+		animations: impl IntoIterator<Item = Self>,
+		create: unsafe extern "C" fn(
+			*const NonNull<sysAnimation>,
+			u32,
+		) -> Option<&'static mut sysAnimation>,
+	) -> Result<Self, ()> {
+		let mut components: Box<[Self]> = Box::empty();
+		for animation in animations {
+			if animation.raw().is_none() {
+				return Err(());
+			}
+			Box::push(&mut components, animation).ok().ok_or(())?;
+		}
+		let mut raw_components = Box::empty();
+		for component in components.iter() {
+			Box::push(&mut raw_components, unsafe {
+				component.raw().unsafe_unwrap()
+			})
+			.ok()
+			.ok_or(())?;
+		}
+
+		// There's not enough memory for that many animations.
+		#[allow(clippy::cast_possible_truncation)]
+		let raw_combined = unsafe { create(raw_components.as_ptr(), raw_components.len() as u32) };
+		if raw_combined.is_some() {
+			// The components are owned by the combined animation now, so only keep their data.
+			// This has to happen before `from_raw`, which destroys the combined animation on failure.
+			for component in components.iter_mut() {
+				component.0.raw_animation = None;
+			}
+		}
+		let mut combined = Self::from_raw(raw_combined)?;
+		combined.0.components = components;
+		Ok(combined)
+	}
+
+	/// Gives up ownership without destroying the animation, so that it can keep playing.
+	///
+	/// The associated data is freed once the animation stops, so only detach scheduled animations.
+	pub fn detach(self)
+	where
+		'a: 'static,
+	{
+		let data = Box::leak(self.into_data());
+		if data.raw_animation.is_some() {
+			data.detached = true;
+		} else {
+			unsafe { drop(Box::<AnimationData>::from_raw(data)) }
+		}
+	}
+
+	fn into_data(self) -> Box<'a, AnimationData<'a>> {
+		let this = ManuallyDrop::new(self);
+		unsafe { ptr::read(&this.0) }
+	}
+
+	fn raw(&self) -> Option<NonNull<sysAnimation>> {
+		self.0.raw_animation
+	}
+
+	/// Runs `f` on the raw animation, unless the system destroyed it already.
+	fn with_raw<R>(&self, f: impl FnOnce(&mut sysAnimation) -> R) -> Option<R> {
+		self.raw()
+			.map(|mut raw_animation| f(unsafe { raw_animation.as_mut() }))
+	}
+
+	/// Sets the handler called when the animation starts playing, after any delay.
+	///
+	/// # Errors
+	///
+	/// Iff `started` can't be moved onto the heap, in which case it's returned.
+	pub fn on_started<F: 'a + FnMut()>(&mut self, started: F) -> Result<(), F> {
+		self.0.started = Some(Box::new(started)?);
+		Ok(())
+	}
+
+	/// Sets the handler called when the animation stops, with whether it finished (rather than being unscheduled).
+	///
+	/// # Errors
+	///
+	/// Iff `stopped` can't be moved onto the heap, in which case it's returned.
+	pub fn on_stopped<F: 'a + FnMut(bool)>(&mut self, stopped: F) -> Result<(), F> {
+		self.0.stopped = Some(Box::new(stopped)?);
+		Ok(())
+	}
+
+	/// Sets the duration in milliseconds. See [`ANIMATION_DURATION_INFINITE`].
+	///
+	/// [`ANIMATION_DURATION_INFINITE`]: ./constant.ANIMATION_DURATION_INFINITE.html
+	pub fn set_duration(&self, duration_ms: u32) {
+		self.with_raw(|raw_animation| unsafe {
+			animation_set_duration(raw_animation, duration_ms)
+		});
+	}
+
+	/// Sets the delay in milliseconds before the animation starts after being scheduled.
+	pub fn set_delay(&self, delay_ms: u32) {
+		self.with_raw(|raw_animation| unsafe { animation_set_delay(raw_animation, delay_ms) });
+	}
+
+	pub fn set_curve(&self, curve: AnimationCurve) {
+		self.with_raw(|raw_animation| unsafe { animation_set_curve(raw_animation, curve) });
+	}
+
+	/// Sets how often the animation plays. See [`ANIMATION_PLAY_COUNT_INFINITE`].
+	///
+	/// [`ANIMATION_PLAY_COUNT_INFINITE`]: ./constant.ANIMATION_PLAY_COUNT_INFINITE.html
+	pub fn set_play_count(&self, play_count: u32) {
+		self.with_raw(|raw_animation| unsafe {
+			animation_set_play_count(raw_animation, play_count)
+		});
+	}
+
+	pub fn set_reverse(&self, reverse: bool) {
+		self.with_raw(|raw_animation| unsafe { animation_set_reverse(raw_animation, reverse) });
+	}
+
+	/// Starts the animation, after its delay.
+	///
+	/// # Errors
+	///
+	/// Iff the animation was destroyed already or couldn't be scheduled.
+	pub fn schedule(&self) -> Result<(), ()> {
+		match self.with_raw(|raw_animation| unsafe { animation_schedule(raw_animation) }) {
+			Some(true) => Ok(()),
+			_ => Err(()),
+		}
+	}
+
+	/// Stops the animation. The system destroys it afterwards.
+	pub fn unschedule(&self) {
+		self.with_raw(|raw_animation| unsafe { animation_unschedule(raw_animation) });
+	}
+
+	#[must_use]
+	pub fn is_scheduled(&self) -> bool {
+		self.with_raw(|raw_animation| unsafe { animation_is_scheduled(raw_animation) })
+			.unwrap_or(false)
+	}
+}
+
+impl<'a> Drop for Animation<'a> {
+	fn drop(&mut self) {
+		if self.is_scheduled() {
+			// Destroys the animation and clears `raw_animation` through the stopped handler.
+			self.unschedule();
+		}
+		if let Some(raw_animation) = self.0.raw_animation.take() {
+			unsafe { animation_destroy(&mut *raw_animation.as_ptr()) };
+		}
+		// The data is dropped after this, as field.
+	}
+}
+
+impl<'a> PropertyAnimation<'a> {
+	/// Animates `layer`'s frame from `from` to `to`, each defaulting to the current frame.
+	///
+	/// # Errors
+	///
+	/// Iff the animation can't be created.
+	pub fn layer_frame(
+		layer: &LayerRef<'a>,
+		from: Option<GRect>,
+		to: Option<GRect>,
+	) -> Result<Self, ()> {
+		let raw_animation = unsafe {
+			property_animation_create_layer_frame(layer.raw(), from.as_ref(), to.as_ref())
+		}
+		.map(|raw_property_animation| unsafe {
+			property_animation_get_animation(raw_property_animation)
+		});
+		Ok(Self(Animation::from_raw(raw_animation)?))
+	}
+
+	/// Animates a custom property through `setter`, from `from` (or, by default, the value `getter` returns when the animation starts) to `to`.
+	///
+	/// # Errors
+	///
+	/// Iff a heap allocation failed or the animation can't be created.
+	pub fn new<V: 'a + Interpolate>(
+		mut getter: impl 'a + FnMut() -> V,
+		mut setter: impl 'a + FnMut(V),
+		from: Option<V>,
+		to: V,
+	) -> Result<Self, ()> {
+		let mut from = from;
+		Animation::with_update(move |progress| {
+			let from = *from.get_or_insert_with(&mut getter);
+			setter(V::interpolate(from, to, progress))
+		})
+		.map(Self)
+	}
+
+	#[must_use]
+	pub fn into_animation(self) -> Animation<'a> {
+		self.0
+	}
+}
+
+impl<'a> From<PropertyAnimation<'a>> for Animation<'a> {
+	fn from(property_animation: PropertyAnimation<'a>) -> Self {
+		property_animation.0
+	}
+}
+
+impl<'a> Deref for PropertyAnimation<'a> {
+	type Target = Animation<'a>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<'a> DerefMut for PropertyAnimation<'a> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+/// Stops all scheduled animations of the app.
+pub fn unschedule_all() {
+	unsafe { animation_unschedule_all() }
+}
@@ -0,0 +1,238 @@
+//! Layers that show a [`Bitmap`], either as is or rotated, and a player for animated images.
+//!
+//! [`Bitmap`]: ../../graphics/struct.Bitmap.html
+
+use super::layers::LayerRef;
+use crate::{
+	foundation::math::Angle,
+	graphics::{
+		graphics_types::{Color8, GAlign, GCompOp, GPoint, GRect},
+		Bitmap, BitmapSequence,
+	},
+	Box, Handle,
+};
+use core::{cell::Cell, marker::PhantomData, ptr::NonNull};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	foundation::timer::{app_timer_cancel, app_timer_register, AppTimer as sysAppTimer},
+	standard_c::memory::void,
+	user_interface::{
+		bitmap_layer::{BitmapLayer as sysBitmapLayer, *},
+		layers::{layer_mark_dirty, Layer as sysLayer},
+		rot_bitmap_layer::{RotBitmapLayer as sysRotBitmapLayer, *},
+	},
+};
+
+/// A layer that draws a bitmap borrowed for `'a`.
+///
+/// `'a` is invariant, so a bitmap can't be set through a shortened lifetime.
+pub struct BitmapLayer<'a>(
+	Handle<'static, sysBitmapLayer>,
+	PhantomData<Cell<&'a Bitmap>>,
+);
+
+/// A layer that draws a bitmap borrowed for `'a`, rotated around a pivot point.
+///
+/// The layer's frame is sized to fit the bitmap at any angle.
+pub struct RotBitmapLayer<'a>(Handle<'static, sysRotBitmapLayer>, PhantomData<&'a Bitmap>);
+
+struct BitmapSequencePlayerData<'a> {
+	sequence: BitmapSequence,
+	frame: Bitmap,
+	layer: Handle<'a, sysBitmapLayer>,
+	/// The timer for the next frame, while playing.
+	timer: Option<NonNull<sysAppTimer>>,
+}
+
+/// Plays a [`BitmapSequence`] in a [`BitmapLayer`], advancing frames with app timers.
+///
+/// The player shows its own frame bitmap in the layer. Playback stops and the layer is cleared when the player is dropped.
+///
+/// [`BitmapSequence`]: ../../graphics/struct.BitmapSequence.html
+/// [`BitmapLayer`]: ./struct.BitmapLayer.html
+pub struct BitmapSequencePlayer<'a>(Box<'a, BitmapSequencePlayerData<'a>>);
+
+impl<'a> BitmapLayer<'a> {
+	/// Creates an empty bitmap layer.
+	///
+	/// # Errors
+	///
+	/// Iff the layer can't be created.
+	pub fn new(frame: GRect) -> Result<Self, ()> {
+		match unsafe { bitmap_layer_create(frame) } {
+			Some(raw_bitmap_layer) => Ok(Self(Handle::new(raw_bitmap_layer), PhantomData)),
+			None => Err(()),
+		}
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { bitmap_layer_get_layer(&*self.0) }))
+	}
+
+	pub fn set_bitmap(&self, bitmap: &'a Bitmap) {
+		unsafe { bitmap_layer_set_bitmap(self.0.as_mut_unchecked(), Some(&*bitmap.0)) }
+	}
+
+	/// Stops showing a bitmap.
+	pub fn clear_bitmap(&self) {
+		unsafe { bitmap_layer_set_bitmap(self.0.as_mut_unchecked(), None) }
+	}
+
+	/// Sets where the bitmap is placed if it's smaller than the layer.
+	pub fn set_alignment(&self, alignment: GAlign) {
+		unsafe { bitmap_layer_set_alignment(self.0.as_mut_unchecked(), alignment) }
+	}
+
+	/// Sets the color of the area not covered by the bitmap.
+	pub fn set_background_color(&self, background_color: Color8) {
+		unsafe { bitmap_layer_set_background_color(self.0.as_mut_unchecked(), background_color) }
+	}
+
+	/// Sets how the bitmap is combined with what's behind it.
+	pub fn set_compositing_mode(&self, mode: GCompOp) {
+		unsafe { bitmap_layer_set_compositing_mode(self.0.as_mut_unchecked(), mode) }
+	}
+}
+
+impl<'a> Drop for BitmapLayer<'a> {
+	fn drop(&mut self) {
+		unsafe { bitmap_layer_destroy(self.0.duplicate().unwrap()) }
+	}
+}
+
+impl<'a> RotBitmapLayer<'a> {
+	/// # Errors
+	///
+	/// Iff the layer can't be created.
+	pub fn new(bitmap: &'a Bitmap) -> Result<Self, ()> {
+		match unsafe { rot_bitmap_layer_create(&*bitmap.0) } {
+			Some(raw_rot_bitmap_layer) => Ok(Self(Handle::new(raw_rot_bitmap_layer), PhantomData)),
+			None => Err(()),
+		}
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		//SAFETY: A rotated bitmap layer starts with its base layer, which the SDK relies on for casting.
+		LayerRef(Handle::new(unsafe {
+			&mut *(self.0.as_mut_unchecked() as *mut sysRotBitmapLayer as *mut sysLayer)
+		}))
+	}
+
+	/// Sets the rotation, clockwise.
+	pub fn set_angle(&self, angle: Angle) {
+		unsafe { rot_bitmap_layer_set_angle(self.0.as_mut_unchecked(), angle.0) }
+	}
+
+	pub fn increment_angle(&self, angle_change: Angle) {
+		unsafe { rot_bitmap_layer_increment_angle(self.0.as_mut_unchecked(), angle_change.0) }
+	}
+
+	/// Sets the point of the bitmap to rotate around, in bitmap coordinates.
+	pub fn set_pivot(&self, pivot: GPoint) {
+		unsafe { rot_bitmap_set_src_ic(self.0.as_mut_unchecked(), pivot) }
+	}
+
+	/// Sets the color of the corners not covered by the rotated bitmap.
+	pub fn set_corner_clip_color(&self, color: Color8) {
+		unsafe { rot_bitmap_layer_set_corner_clip_color(self.0.as_mut_unchecked(), color) }
+	}
+
+	/// Sets how the bitmap is combined with what's behind it.
+	pub fn set_compositing_mode(&self, mode: GCompOp) {
+		unsafe { rot_bitmap_set_compositing_mode(self.0.as_mut_unchecked(), mode) }
+	}
+}
+
+impl<'a> Drop for RotBitmapLayer<'a> {
+	fn drop(&mut self) {
+		unsafe { rot_bitmap_layer_destroy(self.0.duplicate().unwrap()) }
+	}
+}
+
+impl<'a> BitmapSequencePlayer<'a> {
+	/// Creates a stopped player that shows the frames of `sequence` in `layer`.
+	///
+	/// # Errors
+	///
+	/// Iff there's not enough heap memory for the frame bitmap or the player, in which case `sequence` is returned.
+	pub fn new(
+		sequence: BitmapSequence,
+		layer: &'a BitmapLayer<'_>,
+	) -> Result<Self, BitmapSequence> {
+		let frame = match sequence.create_frame_bitmap() {
+			Ok(frame) => frame,
+			Err(()) => return Err(sequence),
+		};
+		let data = Box::new(BitmapSequencePlayerData {
+			sequence,
+			frame,
+			layer: Handle::new(unsafe { layer.0.as_mut_unchecked() }),
+			timer: None,
+		})
+		.map_err(|data| data.sequence)?;
+		unsafe {
+			//SAFETY: The layer is cleared again when the player is dropped.
+			bitmap_layer_set_bitmap(layer.0.as_mut_unchecked(), Some(&*data.frame.0))
+		}
+		Ok(Self(data))
+	}
+
+	#[must_use]
+	pub fn sequence(&self) -> &BitmapSequence {
+		&self.0.sequence
+	}
+
+	#[must_use]
+	pub fn is_playing(&self) -> bool {
+		self.0.timer.is_some()
+	}
+
+	/// Shows the next frame and keeps advancing until the sequence finishes or [`.pause()`](#method.pause) is called.
+	///
+	/// Use [`BitmapSequence::restart`] through [`.sequence()`](#method.sequence) to play it again after it finished.
+	///
+	/// [`BitmapSequence::restart`]: ../../graphics/struct.BitmapSequence.html#method.restart
+	pub fn play(&mut self) {
+		if !self.is_playing() {
+			advance(&mut self.0)
+		}
+	}
+
+	/// Stops advancing frames, keeping the current one on screen.
+	pub fn pause(&mut self) {
+		if let Some(timer) = self.0.timer.take() {
+			unsafe { app_timer_cancel(&mut *timer.as_ptr()) }
+		}
+	}
+}
+
+/// Shows the next frame and schedules the one after it.
+fn advance(data: &mut BitmapSequencePlayerData) {
+	extern "C" fn raw_timer(context: *mut void) {
+		advance(unsafe { &mut *context.cast::<BitmapSequencePlayerData>() })
+	}
+
+	data.timer = None;
+	if let Some(delay_ms) = data.sequence.update_bitmap_next_frame(&mut data.frame) {
+		unsafe { layer_mark_dirty(NonNull::from(bitmap_layer_get_layer(&*data.layer))) }
+		data.timer = unsafe {
+			//SAFETY: The timer is cancelled before `data` is dropped.
+			app_timer_register(
+				delay_ms,
+				raw_timer,
+				data as *mut BitmapSequencePlayerData as *mut void,
+			)
+		}
+		.map(NonNull::from);
+	}
+}
+
+impl<'a> Drop for BitmapSequencePlayer<'a> {
+	fn drop(&mut self) {
+		self.pause();
+		unsafe { bitmap_layer_set_bitmap(self.0.layer.as_mut_unchecked(), None) }
+		// The data is dropped after this, as field.
+	}
+}
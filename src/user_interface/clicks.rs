@@ -0,0 +1,3 @@
+//! Button identifiers shared by the click handling APIs.
+
+pub use pebble_sys::user_interface::clicks::ButtonId;
@@ -1,6 +1,87 @@
-use crate::Handle;
-use core::marker::PhantomData;
+use crate::{graphics::graphics_types::GRect, Handle};
+use core::{marker::PhantomData, ptr::NonNull};
 #[allow(clippy::wildcard_imports)]
-use pebble_sys::user_interface::layers::Layer as sysLayer;
+use pebble_sys::user_interface::layers::{Layer as sysLayer, *};
 
 pub struct Layer<T>(pub(crate) Handle<'static, sysLayer>, PhantomData<T>);
+
+/// A borrowed layer, for example a window's root layer or the base layer of one of the specialised layer types.
+///
+/// Destroying a layer removes it from its parent, so layers can be added to the hierarchy without being borrowed by it.
+#[repr(transparent)]
+pub struct LayerRef<'a>(pub(crate) Handle<'a, sysLayer>);
+
+impl<'a> LayerRef<'a> {
+	pub(crate) fn raw(&self) -> NonNull<sysLayer> {
+		NonNull::from(unsafe { self.0.as_mut_unchecked() })
+	}
+
+	pub fn add_child(&self, child: &LayerRef) {
+		unsafe { layer_add_child(self.raw(), child.raw()) }
+	}
+
+	pub fn insert_below_sibling(&self, below_sibling: &LayerRef) {
+		unsafe { layer_insert_below_sibling(self.raw(), below_sibling.raw()) }
+	}
+
+	pub fn insert_above_sibling(&self, above_sibling: &LayerRef) {
+		unsafe { layer_insert_above_sibling(self.raw(), above_sibling.raw()) }
+	}
+
+	pub fn remove_from_parent(&self) {
+		unsafe { layer_remove_from_parent(self.raw()) }
+	}
+
+	pub fn remove_child_layers(&self) {
+		unsafe { layer_remove_child_layers(self.raw()) }
+	}
+
+	/// Schedules this layer to be redrawn.
+	pub fn mark_dirty(&self) {
+		unsafe { layer_mark_dirty(self.raw()) }
+	}
+
+	#[must_use]
+	pub fn get_frame(&self) -> GRect {
+		unsafe { layer_get_frame(self.raw()) }
+	}
+
+	pub fn set_frame(&self, frame: GRect) {
+		unsafe { layer_set_frame(self.raw(), frame) }
+	}
+
+	#[must_use]
+	pub fn get_bounds(&self) -> GRect {
+		unsafe { layer_get_bounds(self.raw()) }
+	}
+
+	pub fn set_bounds(&self, bounds: GRect) {
+		unsafe { layer_set_bounds(self.raw(), bounds) }
+	}
+
+	/// The bounds, reduced by any part of the screen that's covered by system UI like a Timeline Quick View.
+	///
+	/// See [`unobstructed_area`](crate::foundation::event_service::unobstructed_area) to react to changes.
+	#[must_use]
+	pub fn get_unobstructed_bounds(&self) -> GRect {
+		unsafe { layer_get_unobstructed_bounds(self.raw()) }
+	}
+
+	#[must_use]
+	pub fn get_hidden(&self) -> bool {
+		unsafe { layer_get_hidden(self.raw()) }
+	}
+
+	pub fn set_hidden(&self, hidden: bool) {
+		unsafe { layer_set_hidden(self.raw(), hidden) }
+	}
+
+	#[must_use]
+	pub fn get_clips(&self) -> bool {
+		unsafe { layer_get_clips(self.raw()) }
+	}
+
+	pub fn set_clips(&self, clips: bool) {
+		unsafe { layer_set_clips(self.raw(), clips) }
+	}
+}
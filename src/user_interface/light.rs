@@ -0,0 +1,50 @@
+//! The backlight.
+
+use core::{
+	marker::PhantomData,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+use pebble_sys::user_interface::light::{light_enable, light_enable_interaction};
+
+/// The number of live [`BacklightOnGuard`]s.
+static GUARDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Turns the backlight on as if the user pressed a button, respecting their settings.
+/// It turns off again after the usual timeout.
+pub fn enable_interaction() {
+	unsafe { light_enable_interaction() }
+}
+
+/// Forces the backlight on (`true`) or returns it to automatic control (`false`).
+///
+/// Leaving the backlight on drains the battery quickly. Prefer [`BacklightOnGuard`], which can't be forgotten.
+pub fn enable(enable: bool) {
+	unsafe { light_enable(enable) }
+}
+
+/// Keeps the backlight on while alive.
+/// The backlight returns to automatic control once the last guard is dropped.
+#[must_use = "The backlight returns to automatic control when this guard is dropped."]
+pub struct BacklightOnGuard(PhantomData<*mut ()>);
+
+impl BacklightOnGuard {
+	pub fn new() -> Self {
+		GUARDS.fetch_add(1, Ordering::Relaxed);
+		enable(true);
+		Self(PhantomData)
+	}
+}
+
+impl Default for BacklightOnGuard {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Drop for BacklightOnGuard {
+	fn drop(&mut self) {
+		if GUARDS.fetch_sub(1, Ordering::Relaxed) == 1 {
+			enable(false)
+		}
+	}
+}
@@ -0,0 +1,400 @@
+//! Scrollable lists with sections, backed by a [`MenuDataSource`].
+
+use super::{layers::LayerRef, window::Window};
+use crate::{
+	graphics::{
+		graphics_context::GContext,
+		graphics_types::{Color8, GRect},
+		Bitmap,
+	},
+	standard_c::{CStr, Storage},
+	Box, Handle,
+};
+use core::{
+	cell::Cell,
+	marker::PhantomData,
+	ops::Deref,
+	ptr::{self, NonNull},
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	graphics::graphics_types::GContext as sysGContext,
+	prelude::*,
+	standard_c::memory::void,
+	user_interface::{
+		layers::Layer as sysLayer,
+		menu_layer::{
+			menu_cell_basic_draw as sys_menu_cell_basic_draw,
+			menu_cell_basic_header_draw as sys_menu_cell_basic_header_draw,
+			menu_cell_layer_is_highlighted as sys_menu_cell_layer_is_highlighted,
+			menu_cell_title_draw as sys_menu_cell_title_draw, MenuIndex as sysMenuIndex,
+			MenuLayer as sysMenuLayer, MenuRowAlign as sysMenuRowAlign, *,
+		},
+		window::{
+			window_get_click_config_context, window_set_click_config_provider_with_context,
+			Window as sysWindow,
+		},
+	},
+};
+
+/// The position of a row in a [`MenuLayer`].
+pub type MenuIndex = sysMenuIndex;
+
+/// Where a newly selected row should be scrolled to.
+pub type MenuRowAlign = sysMenuRowAlign;
+
+/// The default height of a row drawn with [`menu_cell_basic_draw`].
+pub const MENU_CELL_BASIC_CELL_HEIGHT: i16 = 44;
+
+/// The default height of a section header drawn with [`menu_cell_basic_header_draw`].
+pub const MENU_CELL_BASIC_HEADER_HEIGHT: i16 = 16;
+
+/// Provides the contents of a [`MenuLayer`] and reacts to its events.
+pub trait MenuDataSource {
+	fn num_sections(&mut self) -> u16 {
+		1
+	}
+
+	fn num_rows(&mut self, section: u16) -> u16;
+
+	fn cell_height(&mut self, _index: MenuIndex) -> i16 {
+		MENU_CELL_BASIC_CELL_HEIGHT
+	}
+
+	/// Sections don't have headers by default.
+	fn header_height(&mut self, _section: u16) -> i16 {
+		0
+	}
+
+	fn draw_row(&mut self, context: &mut GContext, cell_layer: &LayerRef, index: MenuIndex);
+
+	fn draw_header(&mut self, _context: &mut GContext, _cell_layer: &LayerRef, _section: u16) {}
+
+	/// Called when SELECT is clicked on the row at `index`.
+	fn select_click(&mut self, _menu_layer: &MenuLayerRef, _index: MenuIndex) {}
+
+	fn selection_changed(
+		&mut self,
+		_menu_layer: &MenuLayerRef,
+		_new_index: MenuIndex,
+		_old_index: MenuIndex,
+	) {
+	}
+}
+
+pub struct MenuDataSourceWrapper<'a>(Box<'a, dyn 'a + MenuDataSource>);
+
+/// A [`MenuLayer`] as seen from its data source's event callbacks.
+///
+/// Only offers methods that don't call back into the data source, which is already borrowed there.
+#[repr(transparent)]
+pub struct MenuLayerRef<'a>(Handle<'a, sysMenuLayer>);
+
+/// A menu layer that owns its data source.
+///
+/// `'w` is the lifetime of the [`Window`] whose buttons control it, if any.
+pub struct MenuLayer<'w, D: MenuDataSource>(
+	Handle<'static, sysMenuLayer>,
+	*mut MenuDataSourceWrapper<'static>,
+	/// The window this menu layer's click configuration was last set onto.
+	Cell<Option<NonNull<sysWindow>>>,
+	/// `'w` is invariant so that a longer-lived menu layer can't be attached to a shorter-lived window.
+	PhantomData<(D, Cell<&'w ()>)>,
+);
+
+impl<'w, D: 'static + MenuDataSource> MenuLayer<'w, D> {
+	/// Creates a new [`MenuLayer`] with the given frame, moving `data_source` onto the heap.
+	///
+	/// # Errors
+	///
+	/// Iff the data source can't be moved onto the heap or the menu layer can't be created, in which case `data_source` is returned.
+	pub fn new(frame: GRect, data_source: D) -> Result<Self, D> {
+		#![allow(clippy::items_after_statements, clippy::too_many_lines)]
+
+		let data_source_wrapper = Box::leak(
+			Box::new(MenuDataSourceWrapper(Box::new(data_source)?)).map_err(|wrapper| {
+				Box::into_inner(unsafe { Box::downcast_unchecked(wrapper.0) })
+			})?,
+		) as *mut MenuDataSourceWrapper;
+
+		fn data_source_from_context(context: &mut void) -> &mut dyn MenuDataSource {
+			unsafe { &mut *context.cast_unchecked_mut::<MenuDataSourceWrapper>().0 }
+		}
+
+		extern "C" fn raw_get_num_sections(
+			_menu_layer: &mut sysMenuLayer,
+			context: &mut void,
+		) -> u16 {
+			data_source_from_context(context).num_sections()
+		}
+		extern "C" fn raw_get_num_rows(
+			_menu_layer: &mut sysMenuLayer,
+			section_index: u16,
+			context: &mut void,
+		) -> u16 {
+			data_source_from_context(context).num_rows(section_index)
+		}
+		extern "C" fn raw_get_cell_height(
+			_menu_layer: &mut sysMenuLayer,
+			cell_index: &sysMenuIndex,
+			context: &mut void,
+		) -> i16 {
+			data_source_from_context(context).cell_height(*cell_index)
+		}
+		extern "C" fn raw_get_header_height(
+			_menu_layer: &mut sysMenuLayer,
+			section_index: u16,
+			context: &mut void,
+		) -> i16 {
+			data_source_from_context(context).header_height(section_index)
+		}
+		extern "C" fn raw_draw_row(
+			raw_context: &mut sysGContext,
+			cell_layer: NonNull<sysLayer>,
+			cell_index: &sysMenuIndex,
+			context: &mut void,
+		) {
+			let cell_layer = LayerRef(Handle::new(unsafe { &mut *cell_layer.as_ptr() }));
+			data_source_from_context(context).draw_row(
+				&mut GContext::from_raw(raw_context),
+				&cell_layer,
+				*cell_index,
+			)
+		}
+		extern "C" fn raw_draw_header(
+			raw_context: &mut sysGContext,
+			cell_layer: NonNull<sysLayer>,
+			section_index: u16,
+			context: &mut void,
+		) {
+			let cell_layer = LayerRef(Handle::new(unsafe { &mut *cell_layer.as_ptr() }));
+			data_source_from_context(context).draw_header(
+				&mut GContext::from_raw(raw_context),
+				&cell_layer,
+				section_index,
+			)
+		}
+		extern "C" fn raw_select_click(
+			menu_layer: &mut sysMenuLayer,
+			cell_index: &sysMenuIndex,
+			context: &mut void,
+		) {
+			data_source_from_context(context)
+				.select_click(&MenuLayerRef(Handle::new(menu_layer)), *cell_index)
+		}
+		extern "C" fn raw_selection_changed(
+			menu_layer: &mut sysMenuLayer,
+			new_index: sysMenuIndex,
+			old_index: sysMenuIndex,
+			context: &mut void,
+		) {
+			data_source_from_context(context).selection_changed(
+				&MenuLayerRef(Handle::new(menu_layer)),
+				new_index,
+				old_index,
+			)
+		}
+
+		match unsafe { menu_layer_create(frame) } {
+			Some(raw_menu_layer) => {
+				unsafe {
+					//SAFETY: The data source wrapper is only destroyed after the menu layer.
+					menu_layer_set_callbacks(
+						raw_menu_layer,
+						&mut *(data_source_wrapper as *mut void),
+						MenuLayerCallbacks {
+							get_num_sections: Some(raw_get_num_sections),
+							get_num_rows: Some(raw_get_num_rows),
+							get_cell_height: Some(raw_get_cell_height),
+							get_header_height: Some(raw_get_header_height),
+							draw_row: Some(raw_draw_row),
+							draw_header: Some(raw_draw_header),
+							select_click: Some(raw_select_click),
+							select_long_click: None,
+							selection_changed: Some(raw_selection_changed),
+							get_separator_height: None,
+							draw_separator: None,
+							selection_will_change: None,
+							draw_background: None,
+						},
+					)
+				}
+				Ok(Self(
+					Handle::new(raw_menu_layer),
+					data_source_wrapper,
+					Cell::new(None),
+					PhantomData,
+				))
+			}
+			None => Err(Box::into_inner(unsafe {
+				Box::downcast_unchecked(
+					Box::into_inner(Box::<MenuDataSourceWrapper>::from_raw(
+						&mut *data_source_wrapper,
+					))
+					.0,
+				)
+			})),
+		}
+	}
+}
+
+impl<'w, D: MenuDataSource> MenuLayer<'w, D> {
+	#[must_use]
+	pub fn data_source(&self) -> &D {
+		unsafe {
+			//SAFETY: The wrapper was created from a `D` in the constructor.
+			&*(&*(*self.1).0 as *const dyn MenuDataSource as *const D)
+		}
+	}
+
+	pub fn data_source_mut(&mut self) -> &mut D {
+		unsafe {
+			//SAFETY: The wrapper was created from a `D` in the constructor.
+			&mut *(&mut *(*self.1).0 as *mut dyn MenuDataSource as *mut D)
+		}
+	}
+
+	/// Lets `window`'s UP, DOWN and SELECT buttons control this menu layer.
+	///
+	/// The click configuration is removed from `window` again when this menu layer is dropped or set onto another window,
+	/// so `window` may stay on screen afterwards. `window` stays borrowed until then.
+	pub fn set_click_config_onto_window<T: ?Sized>(&self, window: &'w Window<T>) {
+		self.remove_click_config_from_window();
+		let raw_window = unsafe { window.0.as_mut_unchecked() };
+		self.2.set(Some(NonNull::from(&mut *raw_window)));
+		unsafe { menu_layer_set_click_config_onto_window(self.0.as_mut_unchecked(), raw_window) }
+	}
+
+	/// Removes this menu layer's click configuration from the window it was set onto,
+	/// unless that window's click configuration has been replaced since.
+	fn remove_click_config_from_window(&self) {
+		if let Some(mut window) = self.2.take() {
+			unsafe {
+				//SAFETY: The window is borrowed for `'w`, so it's still alive.
+				let context = window_get_click_config_context(window.as_ref());
+				if context == self.0.as_mut_unchecked() as *mut sysMenuLayer as *mut void {
+					window_set_click_config_provider_with_context(
+						window.as_mut(),
+						None,
+						ptr::null_mut::<u8>() as *mut void,
+					);
+				}
+			}
+		}
+	}
+
+	/// Requests all row counts, heights and contents again from the data source.
+	pub fn reload_data(&mut self) {
+		unsafe { menu_layer_reload_data(self.0.as_mut_unchecked()) }
+	}
+
+	/// Calls [`MenuDataSource::selection_changed`] if the selection changes.
+	pub fn set_selected_index(
+		&mut self,
+		index: MenuIndex,
+		scroll_align: MenuRowAlign,
+		animated: bool,
+	) {
+		unsafe {
+			menu_layer_set_selected_index(self.0.as_mut_unchecked(), index, scroll_align, animated)
+		}
+	}
+
+	/// Selects the next row up or down.
+	///
+	/// Calls [`MenuDataSource::selection_changed`] if the selection changes.
+	pub fn set_selected_next(&mut self, up: bool, scroll_align: MenuRowAlign, animated: bool) {
+		unsafe {
+			menu_layer_set_selected_next(self.0.as_mut_unchecked(), up, scroll_align, animated)
+		}
+	}
+}
+
+impl<'a> MenuLayerRef<'a> {
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { menu_layer_get_layer(&*self.0) }))
+	}
+
+	#[must_use]
+	pub fn get_selected_index(&self) -> MenuIndex {
+		unsafe { menu_layer_get_selected_index(&*self.0) }
+	}
+
+	pub fn set_normal_colors(&self, background: Color8, foreground: Color8) {
+		unsafe { menu_layer_set_normal_colors(self.0.as_mut_unchecked(), background, foreground) }
+	}
+
+	pub fn set_highlight_colors(&self, background: Color8, foreground: Color8) {
+		unsafe {
+			menu_layer_set_highlight_colors(self.0.as_mut_unchecked(), background, foreground)
+		}
+	}
+}
+
+impl<'w, D: MenuDataSource> Deref for MenuLayer<'w, D> {
+	type Target = MenuLayerRef<'static>;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe {
+			//SAFETY: Same memory layout, and `MenuLayerRef` doesn't call into the data source.
+			&*(&self.0 as *const Handle<sysMenuLayer> as *const Self::Target)
+		}
+	}
+}
+
+impl<'w, D: MenuDataSource> Drop for MenuLayer<'w, D> {
+	fn drop(&mut self) {
+		self.remove_click_config_from_window();
+		unsafe {
+			//SAFETY: The data source wrapper is created and leaked in the only accessible constructor.
+			//SAFETY: self.0 isn't accessed after this.
+
+			// Destroy the layer, THEN drop its data source.
+			menu_layer_destroy(self.0.duplicate().unwrap());
+			Box::<MenuDataSourceWrapper>::from_raw(&mut *self.1);
+		}
+	}
+}
+
+/// Draws a row with a title and optionally a subtitle and icon, in the system's default style.
+pub fn menu_cell_basic_draw(
+	context: &mut GContext,
+	cell_layer: &LayerRef,
+	title: &CStr<impl Storage>,
+	subtitle: Option<&CStr<impl Storage>>,
+	icon: Option<&Bitmap>,
+) {
+	unsafe {
+		sys_menu_cell_basic_draw(
+			&mut *context.0,
+			&*cell_layer.0,
+			Some(title.as_c_str()),
+			subtitle.map(CStr::as_c_str),
+			icon.map(|icon| icon.0.as_mut_unchecked()),
+		)
+	}
+}
+
+/// Draws a row with only a title, in a larger font than [`menu_cell_basic_draw`].
+pub fn menu_cell_title_draw(
+	context: &mut GContext,
+	cell_layer: &LayerRef,
+	title: &CStr<impl Storage>,
+) {
+	unsafe { sys_menu_cell_title_draw(&mut *context.0, &*cell_layer.0, title.as_c_str()) }
+}
+
+pub fn menu_cell_basic_header_draw(
+	context: &mut GContext,
+	cell_layer: &LayerRef,
+	title: &CStr<impl Storage>,
+) {
+	unsafe { sys_menu_cell_basic_header_draw(&mut *context.0, &*cell_layer.0, title.as_c_str()) }
+}
+
+/// Whether the row being drawn is the selected one, which is drawn with the highlight colours.
+#[must_use]
+pub fn menu_cell_layer_is_highlighted(cell_layer: &LayerRef) -> bool {
+	unsafe { sys_menu_cell_layer_is_highlighted(&*cell_layer.0) }
+}
@@ -1,4 +1,16 @@
+pub mod action_bar_layer;
+pub mod action_menu;
+pub mod animation;
+pub mod bitmap_layer;
+pub mod clicks;
 pub mod layers;
+pub mod light;
+pub mod menu_layer;
+pub mod preferences;
+pub mod scroll_layer;
+pub mod simple_menu_layer;
+pub mod status_bar_layer;
+pub mod text_layer;
 pub mod vibes;
 pub mod window;
 pub mod window_stack;
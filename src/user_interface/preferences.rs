@@ -0,0 +1,15 @@
+//! The user's system-wide display preferences.
+
+use pebble_sys::user_interface::preferences::{
+	preferred_content_size as sys_preferred_content_size,
+	PreferredContentSize as sysPreferredContentSize,
+};
+
+/// How large the user would like content to be, from [`PreferredContentSize::Small`] to [`PreferredContentSize::ExtraLarge`].
+pub type PreferredContentSize = sysPreferredContentSize;
+
+/// The content size set on the watch, for example to pick larger fonts for accessibility.
+#[must_use]
+pub fn preferred_content_size() -> PreferredContentSize {
+	unsafe { sys_preferred_content_size() }
+}
@@ -0,0 +1,226 @@
+//! A layer that scrolls its children, for content taller than the screen.
+
+use super::{layers::LayerRef, window::Window};
+use crate::{
+	graphics::graphics_types::{GPoint, GRect, GSize},
+	Box, Handle,
+};
+use core::{
+	cell::Cell,
+	ptr::{self, NonNull},
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	standard_c::memory::void,
+	user_interface::{
+		scroll_layer::{ScrollLayer as sysScrollLayer, *},
+		window::{
+			window_get_click_config_context, window_set_click_config_provider_with_context,
+			Window as sysWindow,
+		},
+	},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+pub struct ScrollLayerData<O: FnMut(GPoint, &mut T), T> {
+	/// Called with the new content offset whenever it changes, including during animations.
+	pub content_offset_changed: O,
+	pub context: T,
+}
+
+trait ScrollLayerDataTrait<T> {
+	fn content_offset_changed(&mut self, offset: GPoint);
+	fn context(&self) -> &T;
+	fn context_mut(&mut self) -> &mut T;
+}
+
+impl<O: FnMut(GPoint, &mut T), T> ScrollLayerDataTrait<T> for ScrollLayerData<O, T> {
+	fn content_offset_changed(&mut self, offset: GPoint) {
+		(self.content_offset_changed)(offset, &mut self.context)
+	}
+
+	fn context(&self) -> &T {
+		&self.context
+	}
+
+	fn context_mut(&mut self) -> &mut T {
+		&mut self.context
+	}
+}
+
+pub struct ScrollLayerDataWrapper<'a, T>(Box<'a, dyn 'a + ScrollLayerDataTrait<T>>);
+
+/// A scroll layer with context `T`, borrowing its handler's captures and (optionally) a window for `'a`.
+pub struct ScrollLayer<'a, T>(
+	Handle<'static, sysScrollLayer>,
+	*mut ScrollLayerDataWrapper<'a, T>,
+	/// The window this scroll layer's click configuration was last set onto.
+	Cell<Option<NonNull<sysWindow>>>,
+);
+
+impl<'a, T> ScrollLayer<'a, T> {
+	/// # Errors
+	///
+	/// Iff the associated data can't be moved onto the heap or the scroll layer can't be created, in which case `scroll_layer_data` is returned.
+	pub fn new<O: 'a + FnMut(GPoint, &mut T)>(
+		frame: GRect,
+		scroll_layer_data: ScrollLayerData<O, T>,
+	) -> Result<Self, ScrollLayerData<O, T>>
+	where
+		T: 'a,
+	{
+		#![allow(clippy::items_after_statements)]
+
+		let data_wrapper = Box::leak(
+			Box::new(ScrollLayerDataWrapper(Box::new(scroll_layer_data)?)).map_err(|wrapper| {
+				Box::into_inner(unsafe { Box::downcast_unchecked(wrapper.0) })
+			})?,
+		) as *mut ScrollLayerDataWrapper<T>;
+
+		extern "C" fn raw_content_offset_changed<T>(
+			raw_scroll_layer: &mut sysScrollLayer,
+			context: *mut void,
+		) {
+			let offset = unsafe { scroll_layer_get_content_offset(raw_scroll_layer) };
+			#[allow(clippy::cast_ptr_alignment)]
+			let data_wrapper = unsafe {
+				(context as *mut ScrollLayerDataWrapper<T>)
+					.as_mut()
+					.unsafe_unwrap()
+			};
+			data_wrapper.0.content_offset_changed(offset)
+		}
+
+		match unsafe { scroll_layer_create(frame) } {
+			Some(raw_scroll_layer) => {
+				unsafe {
+					//SAFETY: The data wrapper is only destroyed after the scroll layer.
+					scroll_layer_set_context(raw_scroll_layer, data_wrapper as *mut void);
+					scroll_layer_set_callbacks(
+						raw_scroll_layer,
+						ScrollLayerCallbacks {
+							click_config_provider: None,
+							content_offset_changed_handler: Some(raw_content_offset_changed::<T>),
+						},
+					)
+				}
+				Ok(Self(
+					Handle::new(raw_scroll_layer),
+					data_wrapper,
+					Cell::new(None),
+				))
+			}
+			None => Err(Box::into_inner(unsafe {
+				Box::downcast_unchecked(
+					Box::into_inner(Box::<ScrollLayerDataWrapper<T>>::from_raw(
+						&mut *data_wrapper,
+					))
+					.0,
+				)
+			})),
+		}
+	}
+
+	#[must_use]
+	pub fn context(&self) -> &T {
+		unsafe { (*self.1).0.context() }
+	}
+
+	pub fn context_mut(&mut self) -> &mut T {
+		unsafe { (*self.1).0.context_mut() }
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { scroll_layer_get_layer(&*self.0) }))
+	}
+
+	/// Adds `child` to the scrolled content.
+	pub fn add_child(&self, child: &LayerRef) {
+		unsafe { scroll_layer_add_child(self.0.as_mut_unchecked(), child.raw()) }
+	}
+
+	/// Lets `window`'s UP and DOWN buttons scroll this layer.
+	///
+	/// The click configuration is removed from `window` again when this scroll layer is dropped or set onto another window,
+	/// so `window` may stay on screen afterwards. `window` stays borrowed until then.
+	pub fn set_click_config_onto_window<U: ?Sized>(&self, window: &'a Window<U>) {
+		self.remove_click_config_from_window();
+		let raw_window = unsafe { window.0.as_mut_unchecked() };
+		self.2.set(Some(NonNull::from(&mut *raw_window)));
+		unsafe { scroll_layer_set_click_config_onto_window(self.0.as_mut_unchecked(), raw_window) }
+	}
+
+	/// Removes this scroll layer's click configuration from the window it was set onto,
+	/// unless that window's click configuration has been replaced since.
+	fn remove_click_config_from_window(&self) {
+		if let Some(mut window) = self.2.take() {
+			unsafe {
+				//SAFETY: The window is borrowed for `'a`, so it's still alive.
+				let context = window_get_click_config_context(window.as_ref());
+				if context == self.0.as_mut_unchecked() as *mut sysScrollLayer as *mut void {
+					window_set_click_config_provider_with_context(
+						window.as_mut(),
+						None,
+						ptr::null_mut::<u8>() as *mut void,
+					);
+				}
+			}
+		}
+	}
+
+	#[must_use]
+	pub fn get_content_size(&self) -> GSize {
+		unsafe { scroll_layer_get_content_size(&*self.0) }
+	}
+
+	/// May call the `content_offset_changed` handler if the offset has to be clamped.
+	pub fn set_content_size(&mut self, size: GSize) {
+		unsafe { scroll_layer_set_content_size(self.0.as_mut_unchecked(), size) }
+	}
+
+	#[must_use]
+	pub fn get_content_offset(&self) -> GPoint {
+		unsafe { scroll_layer_get_content_offset(self.0.as_mut_unchecked()) }
+	}
+
+	/// Calls the `content_offset_changed` handler, immediately if not `animated`.
+	pub fn set_content_offset(&mut self, offset: GPoint, animated: bool) {
+		unsafe { scroll_layer_set_content_offset(self.0.as_mut_unchecked(), offset, animated) }
+	}
+
+	/// May call the `content_offset_changed` handler if the offset has to be clamped.
+	pub fn set_frame(&mut self, frame: GRect) {
+		unsafe { scroll_layer_set_frame(self.0.as_mut_unchecked(), frame) }
+	}
+
+	#[must_use]
+	pub fn get_paging(&self) -> bool {
+		unsafe { scroll_layer_get_paging(&*self.0) }
+	}
+
+	/// With paging enabled, each click scrolls by the height of the frame instead of a fixed amount.
+	pub fn set_paging(&self, paging_enabled: bool) {
+		unsafe { scroll_layer_set_paging(self.0.as_mut_unchecked(), paging_enabled) }
+	}
+
+	#[must_use]
+	pub fn get_shadow_hidden(&self) -> bool {
+		unsafe { scroll_layer_get_shadow_hidden(&*self.0) }
+	}
+
+	pub fn set_shadow_hidden(&self, hidden: bool) {
+		unsafe { scroll_layer_set_shadow_hidden(self.0.as_mut_unchecked(), hidden) }
+	}
+}
+
+impl<'a, T> Drop for ScrollLayer<'a, T> {
+	fn drop(&mut self) {
+		self.remove_click_config_from_window();
+		unsafe {
+			// Destroy the layer, THEN drop its data.
+			scroll_layer_destroy(self.0.duplicate().unwrap());
+			Box::<ScrollLayerDataWrapper<T>>::from_raw(&mut *self.1);
+		}
+	}
+}
@@ -0,0 +1,238 @@
+//! A ready-made menu over fixed sections of items, each with its own select handler.
+
+use super::{layers::LayerRef, window::Window};
+use crate::{
+	graphics::{graphics_types::GRect, Bitmap},
+	standard_c::{CStr, NotStack, Static},
+	Box, Handle,
+};
+use core::ptr::{self, NonNull};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	standard_c::memory::{c_str, void},
+	user_interface::{
+		menu_layer::{menu_layer_get_selected_index, MenuLayer as sysMenuLayer},
+		simple_menu_layer::{
+			SimpleMenuItem as sysSimpleMenuItem, SimpleMenuLayer as sysSimpleMenuLayer,
+			SimpleMenuSection as sysSimpleMenuSection, *,
+		},
+		window::{
+			window_get_click_config_context, window_set_click_config_provider_with_context,
+			Window as sysWindow,
+		},
+	},
+};
+use unsafe_unwrap::UnsafeUnwrap;
+
+struct Section<'a> {
+	title: Option<&'a c_str>,
+	items: Box<'a, [sysSimpleMenuItem<'a>]>,
+	callbacks: Box<'a, [Box<'a, dyn 'a + FnMut()>]>,
+}
+
+/// Everything the SDK points into while the layer exists.
+struct SimpleMenuData<'a> {
+	sections: Box<'a, [Section<'a>]>,
+	raw_sections: Box<'a, [sysSimpleMenuSection<'a>]>,
+	raw_layer: Option<NonNull<sysSimpleMenuLayer>>,
+}
+
+/// Collects sections and items for a [`SimpleMenuLayer`].
+///
+/// Heap allocation failures are remembered and reported by [`.build(…)`](#method.build).
+#[must_use]
+pub struct SimpleMenuLayerBuilder<'a> {
+	sections: Box<'a, [Section<'a>]>,
+	out_of_memory: bool,
+}
+
+/// A menu layer controlled by a window that's borrowed for `'a`, along with the items' titles and icons.
+pub struct SimpleMenuLayer<'a>(
+	Handle<'static, sysSimpleMenuLayer>,
+	Box<'a, SimpleMenuData<'a>>,
+	NonNull<sysWindow>,
+);
+
+impl<'a> SimpleMenuLayer<'a> {
+	pub fn builder() -> SimpleMenuLayerBuilder<'a> {
+		SimpleMenuLayerBuilder {
+			sections: Box::empty(),
+			out_of_memory: false,
+		}
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe {
+			simple_menu_layer_get_layer(&*self.0)
+		}))
+	}
+
+	/// The row index of the selected item within its section.
+	#[must_use]
+	pub fn get_selected_index(&self) -> i32 {
+		unsafe { simple_menu_layer_get_selected_index(&*self.0) }
+	}
+
+	pub fn set_selected_index(&self, index: i32, animated: bool) {
+		unsafe { simple_menu_layer_set_selected_index(self.0.as_mut_unchecked(), index, animated) }
+	}
+}
+
+impl<'a> SimpleMenuLayerBuilder<'a> {
+	/// Starts a new section. Items are added to the most recently started section.
+	pub fn section(mut self, title: Option<&'a CStr<impl NotStack>>) -> Self {
+		let section = Section {
+			title: title.map(CStr::as_c_str),
+			items: Box::empty(),
+			callbacks: Box::empty(),
+		};
+		if Box::push(&mut self.sections, section).is_err() {
+			self.out_of_memory = true;
+		}
+		self
+	}
+
+	/// Adds an item to the current section, starting an untitled one first if necessary.
+	///
+	/// `select` is called whenever SELECT is clicked while this item is highlighted.
+	pub fn item<F: 'a + FnMut()>(
+		mut self,
+		title: &'a CStr<impl NotStack>,
+		subtitle: Option<&'a CStr<impl NotStack>>,
+		icon: Option<&'a Bitmap>,
+		select: F,
+	) -> Self {
+		if self.sections.is_empty() {
+			self = self.section(None::<&CStr<Static>>);
+		}
+		let section = match (self.out_of_memory, self.sections.last_mut()) {
+			(false, Some(section)) => section,
+			_ => return self,
+		};
+
+		let callback: Box<'a, dyn 'a + FnMut()> = if let Ok(callback) = Box::new(select) {
+			callback
+		} else {
+			self.out_of_memory = true;
+			return self;
+		};
+		let item = sysSimpleMenuItem {
+			title: Some(title.as_c_str()),
+			subtitle: subtitle.map(CStr::as_c_str),
+			icon: icon.map(|icon| NonNull::from(unsafe { icon.0.as_mut_unchecked() })),
+			callback: Some(raw_select),
+		};
+		if Box::push(&mut section.callbacks, callback).is_err()
+			|| Box::push(&mut section.items, item).is_err()
+		{
+			self.out_of_memory = true;
+		}
+		self
+	}
+
+	/// Creates the [`SimpleMenuLayer`] and lets `window`'s buttons control it.
+	///
+	/// The click configuration is removed from `window` again when the menu layer is dropped,
+	/// so `window` may stay on screen afterwards. `window` stays borrowed until then.
+	///
+	/// # Errors
+	///
+	/// Iff any heap allocation failed (also while building) or the layer couldn't be created.
+	#[allow(clippy::cast_possible_truncation)] // There's not enough memory for that many items.
+	#[allow(clippy::cast_possible_wrap)]
+	pub fn build<T: ?Sized>(
+		self,
+		frame: GRect,
+		window: &'a Window<T>,
+	) -> Result<SimpleMenuLayer<'a>, ()> {
+		if self.out_of_memory {
+			return Err(());
+		}
+
+		let mut raw_sections = Box::empty();
+		for section in self.sections.iter() {
+			Box::push(
+				&mut raw_sections,
+				sysSimpleMenuSection {
+					title: section.title,
+					items: section.items.as_ptr(),
+					num_items: section.items.len() as u32,
+				},
+			)
+			.ok()
+			.ok_or(())?;
+		}
+		let mut data = Box::new(SimpleMenuData {
+			sections: self.sections,
+			raw_sections,
+			raw_layer: None,
+		})
+		.ok()
+		.ok_or(())?;
+
+		let raw_window = unsafe { window.0.as_mut_unchecked() };
+		let window_pointer = NonNull::from(&mut *raw_window);
+		match unsafe {
+			//SAFETY: `data` is only dropped after the layer is destroyed.
+			simple_menu_layer_create(
+				frame,
+				raw_window,
+				data.raw_sections.as_ptr(),
+				data.raw_sections.len() as i32,
+				&mut *data as *mut SimpleMenuData as *mut void,
+			)
+		} {
+			Some(raw_layer) => {
+				data.raw_layer = Some(NonNull::from(&mut *raw_layer));
+				Ok(SimpleMenuLayer(
+					Handle::new(raw_layer),
+					data,
+					window_pointer,
+				))
+			}
+			None => Err(()),
+		}
+	}
+}
+
+/// Shared by all items. Looks up the selected item's callback by section and `index`.
+extern "C" fn raw_select(index: i32, context: *mut void) {
+	#[allow(clippy::cast_ptr_alignment)]
+	let data = unsafe { &mut *(context as *mut SimpleMenuData) };
+	let section = unsafe {
+		//SAFETY: This is only called after the layer has been created.
+		let mut raw_layer = data.raw_layer.unsafe_unwrap();
+		menu_layer_get_selected_index(simple_menu_layer_get_menu_layer(raw_layer.as_mut())).section
+	};
+	#[allow(clippy::cast_sign_loss)]
+	let index = index as usize;
+	if let Some(callback) = data
+		.sections
+		.get_mut(section as usize)
+		.and_then(|section| section.callbacks.get_mut(index))
+	{
+		callback()
+	}
+}
+
+impl<'a> Drop for SimpleMenuLayer<'a> {
+	fn drop(&mut self) {
+		unsafe {
+			//SAFETY: The window is borrowed for `'a`, so it's still alive.
+			// Only remove the click configuration if nothing else has replaced it since.
+			let menu_layer = simple_menu_layer_get_menu_layer(self.0.as_mut_unchecked());
+			if window_get_click_config_context(self.2.as_ref())
+				== menu_layer as *mut sysMenuLayer as *mut void
+			{
+				window_set_click_config_provider_with_context(
+					self.2.as_mut(),
+					None,
+					ptr::null_mut::<u8>() as *mut void,
+				);
+			}
+			// The data is dropped after this, as field.
+			simple_menu_layer_destroy(self.0.duplicate().unwrap());
+		}
+	}
+}
@@ -0,0 +1,91 @@
+//! The bar with the time (and optional separator) at the top of most app windows.
+
+use super::layers::LayerRef;
+use crate::{
+	graphics::graphics_types::{Color8, GPoint, GRect, GSize},
+	Handle,
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::user_interface::status_bar_layer::{
+	StatusBarLayer as sysStatusBarLayer,
+	StatusBarLayerSeparatorMode as sysStatusBarLayerSeparatorMode, *,
+};
+
+/// The standard height of a status bar, in pixels.
+pub const STATUS_BAR_LAYER_HEIGHT: i16 = 16;
+
+pub type StatusBarLayerSeparatorMode = sysStatusBarLayerSeparatorMode;
+
+pub struct StatusBarLayer(Handle<'static, sysStatusBarLayer>);
+
+impl StatusBarLayer {
+	/// Creates a status bar spanning the top of the screen.
+	///
+	/// Add it to a window with `window.root_layer().add_child(&status_bar.layer())`.
+	///
+	/// # Errors
+	///
+	/// Iff the status bar can't be created.
+	pub fn new() -> Result<Self, ()> {
+		match unsafe { status_bar_layer_create() } {
+			Some(raw_status_bar) => Ok(Self(Handle::new(raw_status_bar))),
+			None => Err(()),
+		}
+	}
+
+	/// The frame of a status bar at the top of `bounds`.
+	#[must_use]
+	pub fn frame_in(bounds: GRect) -> GRect {
+		GRect {
+			origin: bounds.origin,
+			size: GSize {
+				w: bounds.size.w,
+				h: STATUS_BAR_LAYER_HEIGHT,
+			},
+		}
+	}
+
+	/// The part of `bounds` below a status bar at its top.
+	#[must_use]
+	pub fn content_frame_in(bounds: GRect) -> GRect {
+		GRect {
+			origin: GPoint {
+				x: bounds.origin.x,
+				y: bounds.origin.y + STATUS_BAR_LAYER_HEIGHT,
+			},
+			size: GSize {
+				w: bounds.size.w,
+				h: (bounds.size.h - STATUS_BAR_LAYER_HEIGHT).max(0),
+			},
+		}
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { status_bar_layer_get_layer(&*self.0) }))
+	}
+
+	#[must_use]
+	pub fn get_background_color(&self) -> Color8 {
+		unsafe { status_bar_layer_get_background_color(&*self.0) }
+	}
+
+	#[must_use]
+	pub fn get_foreground_color(&self) -> Color8 {
+		unsafe { status_bar_layer_get_foreground_color(&*self.0) }
+	}
+
+	pub fn set_colors(&self, background: Color8, foreground: Color8) {
+		unsafe { status_bar_layer_set_colors(self.0.as_mut_unchecked(), background, foreground) }
+	}
+
+	pub fn set_separator_mode(&self, mode: StatusBarLayerSeparatorMode) {
+		unsafe { status_bar_layer_set_separator_mode(self.0.as_mut_unchecked(), mode) }
+	}
+}
+
+impl Drop for StatusBarLayer {
+	fn drop(&mut self) {
+		unsafe { status_bar_layer_destroy(self.0.duplicate().unwrap()) }
+	}
+}
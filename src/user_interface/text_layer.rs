@@ -0,0 +1,86 @@
+//! A layer that shows a string.
+
+use super::layers::LayerRef;
+use crate::{
+	graphics::{
+		fonts::Font,
+		graphics_types::{Color8, GRect, GSize},
+		text::{GTextAlignment, GTextOverflowMode},
+	},
+	standard_c::{CStr, Storage},
+	Handle,
+};
+use core::{cell::Cell, marker::PhantomData};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::{
+	standard_c::memory::c_str,
+	user_interface::text_layer::{TextLayer as sysTextLayer, *},
+};
+
+/// A layer that shows text and uses a font, both borrowed for `'a`.
+///
+/// `'a` is invariant, so neither can be set through a shortened lifetime.
+pub struct TextLayer<'a>(
+	Handle<'static, sysTextLayer>,
+	PhantomData<Cell<(&'a c_str, &'a Font)>>,
+);
+
+impl<'a> TextLayer<'a> {
+	/// Creates an empty text layer.
+	///
+	/// # Errors
+	///
+	/// Iff the layer can't be created.
+	pub fn new(frame: GRect) -> Result<Self, ()> {
+		match unsafe { text_layer_create(frame) } {
+			Some(raw_text_layer) => Ok(Self(Handle::new(raw_text_layer), PhantomData)),
+			None => Err(()),
+		}
+	}
+
+	#[must_use]
+	pub fn layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { text_layer_get_layer(&*self.0) }))
+	}
+
+	/// Sets the text to show. It's not copied, so changes to it show up once the layer is redrawn.
+	pub fn set_text(&self, text: &'a CStr<impl Storage>) {
+		unsafe { text_layer_set_text(self.0.as_mut_unchecked(), text.as_c_str()) }
+	}
+
+	pub fn set_font(&self, font: &'a Font) {
+		unsafe { text_layer_set_font(self.0.as_mut_unchecked(), &*font.0) }
+	}
+
+	pub fn set_text_color(&self, color: Color8) {
+		unsafe { text_layer_set_text_color(self.0.as_mut_unchecked(), color) }
+	}
+
+	pub fn set_background_color(&self, color: Color8) {
+		unsafe { text_layer_set_background_color(self.0.as_mut_unchecked(), color) }
+	}
+
+	pub fn set_text_alignment(&self, alignment: GTextAlignment) {
+		unsafe { text_layer_set_text_alignment(self.0.as_mut_unchecked(), alignment) }
+	}
+
+	pub fn set_overflow_mode(&self, overflow_mode: GTextOverflowMode) {
+		unsafe { text_layer_set_overflow_mode(self.0.as_mut_unchecked(), overflow_mode) }
+	}
+
+	/// The size of the laid out text, which may be smaller or (for word wrapped text) larger than the layer.
+	#[must_use]
+	pub fn get_content_size(&self) -> GSize {
+		unsafe { text_layer_get_content_size(self.0.as_mut_unchecked()) }
+	}
+
+	pub fn set_size(&self, max_size: GSize) {
+		unsafe { text_layer_set_size(self.0.as_mut_unchecked(), max_size) }
+	}
+}
+
+impl<'a> Drop for TextLayer<'a> {
+	fn drop(&mut self) {
+		unsafe { text_layer_destroy(self.0.duplicate().unwrap()) }
+	}
+}
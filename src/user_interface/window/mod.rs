@@ -1,9 +1,10 @@
-use super::window_stack;
+use super::{layers::LayerRef, window_stack};
 use crate::{graphics::graphics_types::Color8, Box, Handle, SpecialDrop};
 use core::{
 	marker::PhantomData,
 	mem::ManuallyDrop,
 	ops::{Deref, DerefMut},
+	ptr::NonNull,
 };
 use debugless_unwrap::DebuglessUnwrapNone as _;
 #[allow(clippy::wildcard_imports)]
@@ -60,9 +61,79 @@ impl<L: FnMut() -> T, A: FnMut(&mut T), D: FnMut(&mut T), U: FnMut(T), T> Window
 
 struct WindowData<'a, T> {
 	user_data: Option<T>,
+	/// Set while `user_data` is lent out by [`with_user_data`], which defers window handlers.
+	deferred: Option<DeferredHandlers>,
 	window_handlers: Box<'a, dyn 'a + WindowHandlersTrait<T>>,
 }
 
+/// The net effect of window handler calls that arrived while the user data was lent out.
+#[derive(Clone, Copy, Default)]
+struct DeferredHandlers {
+	/// Whether the window was visible before it first appeared or disappeared, if it did so before being unloaded.
+	was_visible: Option<bool>,
+	/// Whether the window is visible after that, if it wasn't unloaded.
+	visible: Option<bool>,
+	unloaded: bool,
+	/// Whether the window is visible after being loaded again, if it was.
+	reloaded: Option<bool>,
+}
+
+impl DeferredHandlers {
+	fn appear(&mut self) {
+		if self.unloaded {
+			self.reloaded = self.reloaded.map(|_| true);
+		} else {
+			self.was_visible.get_or_insert(false);
+			self.visible = Some(true);
+		}
+	}
+
+	fn disappear(&mut self) {
+		if self.unloaded {
+			self.reloaded = self.reloaded.map(|_| false);
+		} else {
+			self.was_visible.get_or_insert(true);
+			self.visible = Some(false);
+		}
+	}
+
+	fn load(&mut self) {
+		self.reloaded = Some(false);
+	}
+
+	fn unload(&mut self) {
+		self.unloaded = true;
+		self.reloaded = None;
+	}
+}
+
+impl<'a, T> WindowData<'a, T> {
+	/// Calls the window handlers that were deferred while `user_data` was lent out.
+	fn run_deferred(&mut self, deferred: DeferredHandlers) {
+		if deferred.unloaded {
+			let mut user_data = unsafe { self.user_data.take().unsafe_unwrap() };
+			if deferred.was_visible == Some(true) {
+				self.window_handlers.disappear(&mut user_data);
+			}
+			self.window_handlers.unload(user_data);
+			if let Some(visible) = deferred.reloaded {
+				let user_data = self.user_data.get_or_insert(self.window_handlers.load());
+				if visible {
+					self.window_handlers.appear(user_data);
+				}
+			}
+		} else if let (Some(was_visible), Some(visible)) = (deferred.was_visible, deferred.visible)
+		{
+			let user_data = unsafe { self.user_data.as_mut().unsafe_unwrap() };
+			match (was_visible, visible) {
+				(false, true) => self.window_handlers.appear(user_data),
+				(true, false) => self.window_handlers.disappear(user_data),
+				_ => (),
+			}
+		}
+	}
+}
+
 pub struct WindowCreationError<L: FnMut() -> T, A: FnMut(&mut T), D: FnMut(&mut T), U: FnMut(T), T>
 {
 	pub window_handlers: WindowHandlers<L, A, D, U, T>,
@@ -93,6 +164,7 @@ impl<T> Window<T> {
 
 		let window_data = Box::new(WindowData {
 			user_data: None,
+			deferred: None,
 			window_handlers: Box::new(window_handlers)
 				.map_err(|window_handlers| WindowCreationError { window_handlers })?,
 		})
@@ -119,6 +191,9 @@ impl<T> Window<T> {
 					.as_mut()
 					.unsafe_unwrap()
 			};
+			if let Some(deferred) = window_data.deferred.as_mut() {
+				return deferred.load();
+			}
 			window_data
 				.user_data
 				.replace(window_data.window_handlers.load())
@@ -131,6 +206,9 @@ impl<T> Window<T> {
 					.as_mut()
 					.unsafe_unwrap()
 			};
+			if let Some(deferred) = window_data.deferred.as_mut() {
+				return deferred.appear();
+			}
 			window_data
 				.window_handlers
 				.appear(unsafe { window_data.user_data.as_mut().unsafe_unwrap() });
@@ -142,6 +220,9 @@ impl<T> Window<T> {
 					.as_mut()
 					.unsafe_unwrap()
 			};
+			if let Some(deferred) = window_data.deferred.as_mut() {
+				return deferred.disappear();
+			}
 			window_data
 				.window_handlers
 				.disappear(unsafe { window_data.user_data.as_mut().unsafe_unwrap() });
@@ -153,6 +234,9 @@ impl<T> Window<T> {
 					.as_mut()
 					.unsafe_unwrap()
 			};
+			if let Some(deferred) = window_data.deferred.as_mut() {
+				return deferred.unload();
+			}
 			window_data
 				.window_handlers
 				.unload(unsafe { window_data.user_data.take().unsafe_unwrap() });
@@ -230,6 +314,42 @@ impl<T: ?Sized> Window<T> {
 	pub fn set_background_color(&self, background_color: Color8) {
 		unsafe { window_set_background_color(self.0.as_mut_unchecked(), background_color) }
 	}
+
+	/// The layer that contains all other layers shown in this window.
+	#[must_use]
+	pub fn root_layer(&self) -> LayerRef<'_> {
+		LayerRef(Handle::new(unsafe { window_get_root_layer(&*self.0) }))
+	}
+}
+
+/// Calls `f` with the user data of `raw_window`, unless the window isn't loaded or its user data is already lent out.
+///
+/// Window handlers that would run during `f`, for example because `f` pops the window,
+/// are deferred until `f` returns, so the user data can't be dropped while `f` uses it.
+///
+/// # Safety
+///
+/// `raw_window` must belong to a [`Window<T>`] of this exact `T` that isn't dropped during `f`.
+///
+/// [`Window<T>`]: ./struct.Window.html
+pub(crate) unsafe fn with_user_data<T>(raw_window: NonNull<sysWindow>, f: impl FnOnce(&mut T)) {
+	let window_data = window_get_user_data(raw_window.as_ref()).cast::<WindowData<T>>();
+	if (*window_data).deferred.is_some() {
+		return;
+	}
+	let mut user_data = match (*window_data).user_data.take() {
+		Some(user_data) => user_data,
+		None => return,
+	};
+	(*window_data).deferred = Some(DeferredHandlers::default());
+
+	// The window handlers only access `*window_data` during this call, and not `user_data`.
+	f(&mut user_data);
+
+	let window_data = &mut *window_data;
+	window_data.user_data = Some(user_data);
+	let deferred = window_data.deferred.take().unsafe_unwrap();
+	window_data.run_deferred(deferred);
 }
 
 impl<T: ?Sized> Drop for Window<T> {
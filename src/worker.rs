@@ -0,0 +1,20 @@
+//! Entry point APIs for background workers.
+//!
+//! Only available with the `"worker"` feature.
+//! Worker crates must also disable the default `"app"` feature, which adds APIs that workers can't use.
+
+pub use crate::foundation::worker::{
+	send_message, subscribe, AppWorkerMessage, AppWorkerMessageSubscription,
+};
+#[allow(clippy::wildcard_imports)]
+use pebble_sys::worker::*;
+
+/// Runs the worker's event loop until the worker is asked to exit.
+pub fn event_loop() {
+	unsafe { worker_event_loop() }
+}
+
+/// Launches the foreground app this worker belongs to.
+pub fn launch_app() {
+	unsafe { worker_launch_app() }
+}
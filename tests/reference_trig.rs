@@ -0,0 +1,143 @@
+//! Host tests for the pure-Rust trigonometry behind the `"reference-trig"` feature.
+//!
+//! The library itself only builds for the watch, so this includes the implementation directly:
+//!
+//! ```sh
+//! rustc --edition 2018 --test tests/reference_trig.rs -o target/reference_trig && target/reference_trig
+//! ```
+
+#![allow(clippy::cast_possible_truncation)]
+
+/// Same as `foundation::math::TRIG_MAX_ANGLE`.
+const TRIG_MAX_ANGLE: i32 = 0x1_0000;
+/// Same as `foundation::math::TRIG_MAX_RATIO`.
+const TRIG_MAX_RATIO: i32 = 0xffff;
+
+#[path = "../src/foundation/math/reference.rs"]
+mod reference;
+
+use std::f64::consts::PI;
+
+const QUARTER: i32 = TRIG_MAX_ANGLE / 4;
+
+/// The exact value, rounded to the nearest fixed-point step.
+fn exact_sin(angle: i32) -> i32 {
+	let radians = f64::from(angle) * 2. * PI / f64::from(TRIG_MAX_ANGLE);
+	(radians.sin() * f64::from(TRIG_MAX_RATIO)).round() as i32
+}
+
+#[test]
+fn sin_at_quarter_turns() {
+	assert_eq!(reference::sin(0), 0);
+	assert_eq!(reference::sin(QUARTER), TRIG_MAX_RATIO);
+	assert_eq!(reference::sin(2 * QUARTER), 0);
+	assert_eq!(reference::sin(3 * QUARTER), -TRIG_MAX_RATIO);
+	assert_eq!(reference::sin(TRIG_MAX_ANGLE), 0);
+	assert_eq!(reference::sin(-QUARTER), -TRIG_MAX_RATIO);
+}
+
+#[test]
+fn cos_at_quarter_turns() {
+	assert_eq!(reference::cos(0), TRIG_MAX_RATIO);
+	assert_eq!(reference::cos(QUARTER), 0);
+	assert_eq!(reference::cos(2 * QUARTER), -TRIG_MAX_RATIO);
+	assert_eq!(reference::cos(3 * QUARTER), 0);
+}
+
+#[test]
+fn sin_at_eighth_turn() {
+	// TRIG_MAX_RATIO * √2 / 2 = 46340.2…
+	assert_eq!(reference::sin(TRIG_MAX_ANGLE / 8), 46340);
+}
+
+#[test]
+fn sin_matches_the_sdk_table() {
+	// The first entries of the SDK's sine table.
+	let sdk = [0, 6, 13, 19, 25, 31, 38, 44, 50, 57];
+	for (angle, &expected) in sdk.iter().enumerate() {
+		assert_eq!(reference::sin(angle as i32), expected);
+		assert_eq!(reference::sin(-(angle as i32)), -expected);
+	}
+	assert_eq!(reference::sin(1000), 6273);
+	assert_eq!(reference::sin(5461), 32766); // 30°, rounded down to whole angle steps.
+	assert_eq!(reference::sin(10923), 56756); // 60°, rounded up.
+	assert_eq!(reference::sin(12345), 60683);
+	assert_eq!(reference::sin(QUARTER - 1), TRIG_MAX_RATIO);
+	assert_eq!(reference::sin(2 * QUARTER + 1000), -6273);
+	assert_eq!(reference::cos(QUARTER + 1000), -6273);
+}
+
+#[test]
+fn atan2_matches_known_outputs() {
+	assert_eq!(reference::atan2(3, 4), 6712);
+	assert_eq!(reference::atan2(1, 2), 4836);
+	assert_eq!(reference::atan2(100, -1), 16488);
+	assert_eq!(reference::atan2(-5, 12), 61418);
+}
+
+#[test]
+fn sin_matches_exact_values_over_a_full_turn() {
+	for angle in 0..TRIG_MAX_ANGLE {
+		let difference = (reference::sin(angle) - exact_sin(angle)).abs();
+		assert!(difference <= 1, "sin({}) is off by {}", angle, difference);
+	}
+}
+
+#[test]
+fn sin_and_cos_are_periodic() {
+	for &angle in &[0, 1, 1234, QUARTER + 7, 40_000] {
+		assert_eq!(
+			reference::sin(angle),
+			reference::sin(angle + TRIG_MAX_ANGLE)
+		);
+		assert_eq!(
+			reference::sin(angle),
+			reference::sin(angle - 3 * TRIG_MAX_ANGLE)
+		);
+		assert_eq!(
+			reference::cos(angle),
+			reference::cos(angle + TRIG_MAX_ANGLE)
+		);
+	}
+}
+
+#[test]
+fn cos_does_not_overflow_for_large_angles() {
+	assert_eq!(
+		reference::cos(i32::MAX),
+		reference::cos(i32::MAX % TRIG_MAX_ANGLE)
+	);
+	assert_eq!(reference::cos(i32::MIN), TRIG_MAX_RATIO);
+}
+
+#[test]
+fn atan2_on_axes_and_diagonals() {
+	assert_eq!(reference::atan2(0, 0), 0);
+	assert_eq!(reference::atan2(0, 1), 0);
+	assert_eq!(reference::atan2(1, 1), TRIG_MAX_ANGLE / 8);
+	assert_eq!(reference::atan2(1, 0), QUARTER);
+	assert_eq!(reference::atan2(1, -1), 3 * TRIG_MAX_ANGLE / 8);
+	assert_eq!(reference::atan2(0, -1), 2 * QUARTER);
+	assert_eq!(reference::atan2(-1, -1), 5 * TRIG_MAX_ANGLE / 8);
+	assert_eq!(reference::atan2(-1, 0), 3 * QUARTER);
+	assert_eq!(reference::atan2(-1, 1), 7 * TRIG_MAX_ANGLE / 8);
+	assert_eq!(reference::atan2(i16::MIN, i16::MIN), 5 * TRIG_MAX_ANGLE / 8);
+}
+
+#[test]
+fn atan2_inverts_sin_and_cos() {
+	for angle in (0..TRIG_MAX_ANGLE).step_by(7) {
+		// Halved to fit `i16`.
+		let y = (reference::sin(angle) / 2) as i16;
+		let x = (reference::cos(angle) / 2) as i16;
+		let result = reference::atan2(y, x);
+		let difference =
+			(result - angle + TRIG_MAX_ANGLE / 2).rem_euclid(TRIG_MAX_ANGLE) - TRIG_MAX_ANGLE / 2;
+		assert!(
+			difference.abs() <= 1,
+			"atan2 of angle {} is {}",
+			angle,
+			result
+		);
+	}
+}